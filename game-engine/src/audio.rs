@@ -9,28 +9,48 @@ use web_sys::CustomEvent;
 
 // Import events for user gesture handling
 use crate::events::{JsToBevyEvent, SharedSettings};
+use crate::requests::{RequestStatus, RequestTracker};
+use crate::resources::AudioGate;
 
-// Simple console logging macros for WASM
+// Simple console logging macros for WASM; native builds (tests) have no console and drop the message
+#[cfg(target_arch = "wasm32")]
 macro_rules! console_log {
     ($($arg:tt)*) => {
         web_sys::console::log_1(&format!($($arg)*).into())
     };
 }
 
+#[cfg(target_arch = "wasm32")]
 macro_rules! console_warn {
     ($($arg:tt)*) => {
         web_sys::console::warn_1(&format!($($arg)*).into())
     };
 }
 
+#[cfg(target_arch = "wasm32")]
 macro_rules! console_error {
     ($($arg:tt)*) => {
         web_sys::console::error_1(&format!($($arg)*).into())
     };
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_log {
+    ($($arg:tt)*) => ({ let _ = format_args!($($arg)*); })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_warn {
+    ($($arg:tt)*) => ({ let _ = format_args!($($arg)*); })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_error {
+    ($($arg:tt)*) => ({ let _ = format_args!($($arg)*); })
+}
+
 /// Audio files for different game contexts
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AudioContext {
     /// Sounds when entering game areas
     Enter,
@@ -46,6 +66,30 @@ pub enum AudioContext {
     Test,
 }
 
+impl AudioContext {
+    pub const ALL: [AudioContext; 6] = [
+        AudioContext::Enter,
+        AudioContext::Exit,
+        AudioContext::UI,
+        AudioContext::Critter,
+        AudioContext::Ambient,
+        AudioContext::Test,
+    ];
+
+    /// Parse a context name from JS (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "enter" => Some(AudioContext::Enter),
+            "exit" => Some(AudioContext::Exit),
+            "ui" => Some(AudioContext::UI),
+            "critter" => Some(AudioContext::Critter),
+            "ambient" => Some(AudioContext::Ambient),
+            "test" => Some(AudioContext::Test),
+            _ => None,
+        }
+    }
+}
+
 /// Audio playback requests sent from Bevy to TypeScript
 #[derive(Debug, Clone, Serialize, Deserialize, Event)]
 #[serde(tag = "type")]
@@ -57,6 +101,9 @@ pub enum AudioRequest {
         context: AudioContext,
         volume: f32,
         loop_audio: bool,
+        /// Concrete URL picked from the sound's registered formats (JS falls back to its own lookup)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source_url: Option<String>,
     },
     /// Stop currently playing audio
     Stop {
@@ -73,6 +120,41 @@ pub enum AudioRequest {
         request_id: String,
         test_type: String,
     },
+    /// Play the registered sound for a context (resolved in Bevy before forwarding to JS)
+    PlayContext {
+        request_id: String,
+        context: AudioContext,
+        volume: Option<f32>,
+    },
+    /// Drop pending requests that never got a response (handled in Bevy, not forwarded to JS)
+    FlushStaleRequests {
+        request_id: String,
+    },
+    /// Set the volume bus for one audio context (handled in Bevy, not forwarded to JS)
+    SetContextVolume {
+        request_id: String,
+        context: AudioContext,
+        volume: f32,
+    },
+    /// Cap simultaneous one-shot voices (handled in Bevy, not forwarded to JS)
+    SetMaxVoices {
+        request_id: String,
+        max_voices: usize,
+    },
+    /// Global mute (handled in Bevy, which forwards the Pause/Resume/Stop it implies)
+    SetMuted {
+        request_id: String,
+        muted: bool,
+    },
+    /// Pause a sound where it is, keeping its position for Resume
+    Pause {
+        request_id: String,
+        sound_id: String,
+    },
+    Resume {
+        request_id: String,
+        sound_id: String,
+    },
 }
 
 /// Audio responses sent from TypeScript back to Bevy
@@ -103,15 +185,73 @@ pub enum AudioResponse {
     },
 }
 
+impl AudioRequest {
+    pub fn request_id(&self) -> &str {
+        match self {
+            AudioRequest::Play { request_id, .. }
+            | AudioRequest::Stop { request_id, .. }
+            | AudioRequest::SetVolume { request_id, .. }
+            | AudioRequest::Test { request_id, .. }
+            | AudioRequest::PlayContext { request_id, .. }
+            | AudioRequest::FlushStaleRequests { request_id }
+            | AudioRequest::SetContextVolume { request_id, .. }
+            | AudioRequest::SetMaxVoices { request_id, .. }
+            | AudioRequest::SetMuted { request_id, .. }
+            | AudioRequest::Pause { request_id, .. }
+            | AudioRequest::Resume { request_id, .. } => request_id,
+        }
+    }
+
+    /// Kind recorded in the shared `RequestTracker`
+    pub fn tracker_kind(&self) -> &'static str {
+        match self {
+            AudioRequest::Play { .. } | AudioRequest::PlayContext { .. } => "audio:play",
+            AudioRequest::Stop { .. } => "audio:stop",
+            AudioRequest::SetVolume { .. } => "audio:set_volume",
+            AudioRequest::Test { .. } => "audio:test",
+            AudioRequest::FlushStaleRequests { .. } => "audio:flush",
+            AudioRequest::SetContextVolume { .. } => "audio:set_context_volume",
+            AudioRequest::SetMaxVoices { .. } => "audio:set_max_voices",
+            AudioRequest::SetMuted { .. } => "audio:set_muted",
+            AudioRequest::Pause { .. } => "audio:pause",
+            AudioRequest::Resume { .. } => "audio:resume",
+        }
+    }
+}
+
+impl AudioResponse {
+    /// Request id plus the tracker outcome this response settles it with
+    pub fn outcome(&self) -> (&str, RequestStatus) {
+        match self {
+            AudioResponse::PlayCompleted { request_id, success: false, error_message, .. } => (
+                request_id,
+                RequestStatus::Failed(error_message.clone().unwrap_or_else(|| "Unknown error".to_string())),
+            ),
+            AudioResponse::Stopped { request_id, success: false } => (
+                request_id,
+                RequestStatus::Failed("stop failed".to_string()),
+            ),
+            AudioResponse::PlayCompleted { request_id, .. }
+            | AudioResponse::Stopped { request_id, .. }
+            | AudioResponse::VolumeChanged { request_id, .. }
+            | AudioResponse::TestCompleted { request_id, .. } => (request_id, RequestStatus::Resolved),
+        }
+    }
+}
+
 /// Resource managing audio state and requests
 #[derive(Resource)]
 pub struct AudioManager {
-    /// Currently playing sounds
+    /// Currently playing sounds, keyed by the request that started them
     pub playing_sounds: HashMap<String, PlayingSound>,
-    /// Pending requests waiting for response
-    pub pending_requests: HashMap<String, PendingAudioRequest>,
+    /// One-shot voices allowed at once; the oldest is stolen beyond this (looping tracks don't count)
+    pub max_voices: usize,
+    /// Requests still waiting on JS (tracked in the shared `RequestTracker`) older than this are reaped as failures (ms)
+    pub pending_timeout_ms: f64,
     /// Global volume setting (0.0 to 1.0)
     pub global_volume: f32,
+    /// Per-context volume buses (0.0 to 1.0), applied on top of the global volume
+    pub context_volumes: HashMap<AudioContext, f32>,
     /// Audio context mappings (sound_id -> file path)
     pub sound_registry: HashMap<String, AudioFileInfo>,
     /// Error state
@@ -119,6 +259,8 @@ pub struct AudioManager {
     pub error_count: u32,
     /// Audio gate for user gesture requirement
     pub gesture_enabled: bool,
+    /// Global mute: nothing is heard and new plays are dropped; volume settings are kept for unmuting
+    pub muted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -128,13 +270,7 @@ pub struct PlayingSound {
     pub volume: f32,
     pub started_at: f64,
     pub is_looping: bool,
-}
-
-#[derive(Debug, Clone)]
-pub struct PendingAudioRequest {
-    pub request: AudioRequest,
-    pub timestamp: f64,
-    pub retry_count: u32,
+    pub ends_at: Option<f64>, // ms, known once JS reports the duration (PlayCompleted arrives as playback starts)
 }
 
 #[derive(Debug, Clone)]
@@ -143,6 +279,7 @@ pub struct AudioFileInfo {
     pub context: AudioContext,
     pub default_volume: f32,
     pub format: AudioFormat,
+    pub alternatives: Vec<String>, // extra encodings of the same sound, tried after file_path
 }
 
 #[derive(Debug, Clone)]
@@ -172,6 +309,7 @@ impl Default for AudioManager {
             context: AudioContext::Enter,
             default_volume: 0.8,
             format: AudioFormat::Mp3,
+            alternatives: Vec::new(),
         });
         
         sound_registry.insert("exit_area".to_string(), AudioFileInfo {
@@ -179,6 +317,7 @@ impl Default for AudioManager {
             context: AudioContext::Exit,
             default_volume: 0.7,
             format: AudioFormat::Mp3,
+            alternatives: Vec::new(),
         });
         
         sound_registry.insert("yipee".to_string(), AudioFileInfo {
@@ -186,6 +325,7 @@ impl Default for AudioManager {
             context: AudioContext::Test,
             default_volume: 0.8,
             format: AudioFormat::Auto,
+            alternatives: Vec::new(),
         });
         
         sound_registry.insert("button_click".to_string(), AudioFileInfo {
@@ -193,16 +333,20 @@ impl Default for AudioManager {
             context: AudioContext::UI,
             default_volume: 0.6,
             format: AudioFormat::Mp3,
+            alternatives: Vec::new(),
         });
 
         Self {
             playing_sounds: HashMap::new(),
-            pending_requests: HashMap::new(),
+            max_voices: 8,
+            pending_timeout_ms: 30_000.0,
             global_volume: 1.0,
+            context_volumes: AudioContext::ALL.iter().map(|c| (c.clone(), 1.0)).collect(),
             sound_registry,
             last_error: None,
             error_count: 0,
             gesture_enabled: false,
+            muted: false,
         }
     }
 }
@@ -213,27 +357,77 @@ impl AudioManager {
         format!("audio-{}", js_sys::Date::now() as u64)
     }
     
+    /// Volume bus gain for a context (defaults to 1.0)
+    pub fn context_volume(&self, context: &AudioContext) -> f32 {
+        self.context_volumes.get(context).copied().unwrap_or(1.0)
+    }
+    
+    /// Mix from shared settings: sfx_volume is the master gain and a context bus changes only when the
+    /// settings carry a volume for it; other buses keep what set_context_volume gave them
+    pub fn apply_settings(&mut self, settings: &SharedSettings) {
+        self.global_volume = settings.sfx_volume.clamp(0.0, 1.0);
+        for context in AudioContext::ALL {
+            if let Some(volume) = settings.context_volume(&context) {
+                self.context_volumes.insert(context, volume);
+            }
+        }
+    }
+
+    /// Set the volume bus gain for a context
+    pub fn set_context_volume(&mut self, context: AudioContext, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+        console_log!("🎚️ Context volume {:?} set to {:.2}", context, volume);
+        self.context_volumes.insert(context, volume);
+    }
+    
+    /// Effective playback volume for a registered sound (requested/default * global * context bus; 0 while muted)
+    pub fn effective_volume(&self, sound_id: &str, volume: Option<f32>) -> Option<f32> {
+        self.sound_registry.get(sound_id).map(|sound_info| {
+            volume.unwrap_or(sound_info.default_volume) * self.bus_gain(&sound_info.context)
+        })
+    }
+
+    /// Gain the mix applies to anything playing on `context`: global * context bus, 0 while muted
+    pub fn bus_gain(&self, context: &AudioContext) -> f32 {
+        if self.muted { 0.0 } else { self.global_volume * self.context_volume(context) }
+    }
+
+    /// Mute or unmute all audio. Returns what JS has to do about voices already playing: looping tracks
+    /// pause (and resume on unmute), one-shots are cut off
+    pub fn set_muted(&mut self, muted: bool, request_id: &str) -> Vec<AudioRequest> {
+        if self.muted == muted {
+            return Vec::new();
+        }
+        self.muted = muted;
+        let mut voices: Vec<(String, PlayingSound)> = self.playing_sounds.iter().map(|(id, v)| (id.clone(), v.clone())).collect();
+        voices.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut follow_ups = Vec::new();
+        for (voice_id, voice) in voices {
+            let request_id = format!("{}-{}", request_id, voice_id);
+            let sound_id = voice.sound_id;
+            match (muted, voice.is_looping) {
+                (true, true) => follow_ups.push(AudioRequest::Pause { request_id, sound_id }),
+                (false, true) => follow_ups.push(AudioRequest::Resume { request_id, sound_id }),
+                (true, false) => {
+                    self.playing_sounds.remove(&voice_id);
+                    follow_ups.push(AudioRequest::Stop { request_id, sound_id: Some(sound_id) });
+                }
+                (false, false) => {}
+            }
+        }
+        follow_ups
+    }
+
+    /// Whether a request would start new sound and must be dropped because audio is muted
+    pub fn blocked_by_mute(&self, request: &AudioRequest) -> bool {
+        self.muted && matches!(request, AudioRequest::Play { .. } | AudioRequest::PlayContext { .. } | AudioRequest::Test { .. })
+    }
+    
     /// Play a sound by ID
     pub fn play_sound(&mut self, sound_id: &str, volume: Option<f32>) -> String {
         let request_id = Self::generate_request_id();
         
-        if let Some(sound_info) = self.sound_registry.get(sound_id) {
-            let effective_volume = volume.unwrap_or(sound_info.default_volume) * self.global_volume;
-            
-            let request = AudioRequest::Play {
-                request_id: request_id.clone(),
-                sound_id: sound_id.to_string(),
-                context: sound_info.context.clone(),
-                volume: effective_volume,
-                loop_audio: false,
-            };
-            
-            self.pending_requests.insert(request_id.clone(), PendingAudioRequest {
-                request: request.clone(),
-                timestamp: js_sys::Date::now(),
-                retry_count: 0,
-            });
-            
+        if let Some(effective_volume) = self.effective_volume(sound_id, volume) {
             console_log!("🎵 Playing sound: {} (volume: {:.2})", sound_id, effective_volume);
         } else {
             console_warn!("🎵 Sound not found in registry: {}", sound_id);
@@ -243,6 +437,45 @@ impl AudioManager {
         request_id
     }
     
+    /// Track a voice that just started, stealing the oldest one-shots while over `max_voices`.
+    /// Returns the stolen voices (request id + sound) so they can be stopped in JS.
+    pub fn register_voice(&mut self, request_id: &str, voice: PlayingSound) -> Vec<(String, PlayingSound)> {
+        let now = voice.started_at;
+        self.playing_sounds.retain(|_, v| v.is_looping || v.ends_at.is_none_or(|end| end > now));
+        self.playing_sounds.insert(request_id.to_string(), voice);
+        let mut stolen = Vec::new();
+        while self.playing_sounds.values().filter(|v| !v.is_looping).count() > self.max_voices {
+            let oldest = self.playing_sounds
+                .iter()
+                .filter(|(id, v)| !v.is_looping && id.as_str() != request_id)
+                .min_by(|a, b| a.1.started_at.total_cmp(&b.1.started_at).then_with(|| a.0.cmp(b.0)))
+                .map(|(id, _)| id.clone());
+            let Some(id) = oldest else { break };
+            if let Some(voice) = self.playing_sounds.remove(&id) {
+                stolen.push((id, voice));
+            }
+        }
+        stolen
+    }
+    
+    /// Pick the registered sound for a context (lowest sound id wins, so the choice is stable)
+    pub fn sound_for_context(&self, context: &AudioContext) -> Option<String> {
+        self.sound_registry
+            .iter()
+            .filter(|(_, info)| &info.context == context)
+            .map(|(id, _)| id.clone())
+            .min()
+    }
+    
+    /// Resolve the URL to play for a sound: the first registered encoding the platform can decode
+    pub fn preferred_source(&self, sound_id: &str, can_play: impl Fn(&str) -> bool) -> Option<String> {
+        let info = self.sound_registry.get(sound_id)?;
+        let sources: Vec<&str> = std::iter::once(info.file_path.as_str())
+            .chain(info.alternatives.iter().map(String::as_str))
+            .collect();
+        select_playable_source(&sources, can_play).map(str::to_string)
+    }
+    
     /// Play enter area sound
     pub fn play_enter_sound(&mut self) -> String {
         console_log!("🚪 Playing enter area sound");
@@ -265,22 +498,24 @@ impl AudioManager {
     pub fn handle_response(&mut self, response: AudioResponse) {
         match response {
             AudioResponse::PlayCompleted { request_id, success, duration_seconds, error_message } => {
-                if let Some(pending) = self.pending_requests.remove(&request_id) {
-                    if success {
-                        console_log!("✅ Audio completed: {} ({:.1}s)", 
-                            self.get_sound_id_from_request(&pending.request).unwrap_or("unknown".to_string()),
-                            duration_seconds.unwrap_or(0.0)
-                        );
-                        self.error_count = 0; // Reset error count on success
-                    } else {
-                        let error_msg = error_message.unwrap_or("Unknown error".to_string());
-                        console_warn!("❌ Audio failed: {}", error_msg);
-                        self.handle_error(AudioError::PlaybackFailed(error_msg));
-                    }
+                if !success {
+                    self.playing_sounds.remove(&request_id);
+                } else if let (Some(duration), Some(voice)) = (duration_seconds, self.playing_sounds.get_mut(&request_id)) {
+                    voice.ends_at = Some(voice.started_at + duration as f64 * 1000.0);
+                }
+                if success {
+                    console_log!("✅ Audio completed: {} ({:.1}s)", 
+                        self.playing_sounds.get(&request_id).map_or("unknown", |voice| voice.sound_id.as_str()),
+                        duration_seconds.unwrap_or(0.0)
+                    );
+                    self.error_count = 0; // Reset error count on success
+                } else {
+                    let error_msg = error_message.unwrap_or("Unknown error".to_string());
+                    console_warn!("❌ Audio failed: {}", error_msg);
+                    self.handle_error(AudioError::PlaybackFailed(error_msg));
                 }
             }
             AudioResponse::Stopped { request_id, success } => {
-                self.pending_requests.remove(&request_id);
                 if success {
                     console_log!("⏹️ Audio stopped: {}", request_id);
                 }
@@ -295,12 +530,6 @@ impl AudioManager {
         }
     }
     
-    fn get_sound_id_from_request(&self, request: &AudioRequest) -> Option<String> {
-        match request {
-            AudioRequest::Play { sound_id, .. } => Some(sound_id.clone()),
-            _ => None,
-        }
-    }
     
     /// Handle audio errors with backoff
     pub fn handle_error(&mut self, error: AudioError) {
@@ -323,6 +552,25 @@ impl AudioManager {
         }
     }
     
+    /// Count requests that never got a response as failures and drop their local state
+    pub fn fail_timed_out(&mut self, request_ids: &[String]) -> usize {
+        for request_id in request_ids {
+            self.playing_sounds.remove(request_id);
+        }
+        let reaped = request_ids.len();
+        if reaped > 0 {
+            self.error_count += reaped as u32;
+            self.last_error = Some(AudioError::PlaybackFailed(format!("{} request(s) timed out without response", reaped)));
+        }
+        reaped
+    }
+    
+    /// Reap audio requests the shared tracker has waited on past the timeout
+    pub fn reap_timed_out(&mut self, tracker: &mut RequestTracker, now: f64) -> usize {
+        let timed_out = tracker.reap_timed_out("audio", now, self.pending_timeout_ms);
+        self.fail_timed_out(&timed_out)
+    }
+    
     /// Enable audio after user gesture
     pub fn enable_audio_gesture(&mut self) {
         self.gesture_enabled = true;
@@ -334,8 +582,39 @@ impl AudioManager {
 pub fn dispatch_audio_requests(
     mut audio_requests: EventReader<AudioRequest>,
     mut audio_manager: ResMut<AudioManager>,
+    mut tracker: ResMut<RequestTracker>,
+    mut audio_gate: Option<ResMut<AudioGate>>,
 ) {
     for request in audio_requests.read() {
+        // Volume bus changes are engine-side state, no gesture or JS round-trip needed
+        if let AudioRequest::SetContextVolume { context, volume, .. } = request {
+            audio_manager.set_context_volume(context.clone(), *volume);
+            continue;
+        }
+        if let AudioRequest::SetMaxVoices { max_voices, .. } = request {
+            audio_manager.max_voices = *max_voices;
+            console_log!("🎚️ Max audio voices set to {}", max_voices);
+            continue;
+        }
+        if let AudioRequest::SetMuted { request_id, muted } = request {
+            // Catalog sounds play outside the AudioManager; the gate carries the mute to them
+            if let Some(gate) = audio_gate.as_mut() {
+                gate.muted = *muted;
+            }
+            console_log!("{} Audio {}", if *muted { "🔇" } else { "🔊" }, if *muted { "muted" } else { "unmuted" });
+            for follow_up in audio_manager.set_muted(*muted, request_id) {
+                if let Err(e) = send_audio_request_to_js(&follow_up) {
+                    console_error!("Failed to apply mute to {}: {:?}", follow_up.request_id(), e);
+                }
+            }
+            continue;
+        }
+        if let AudioRequest::FlushStaleRequests { .. } = request {
+            let reaped = audio_manager.reap_timed_out(&mut tracker, js_sys::Date::now());
+            console_log!("🧹 Manual audio flush reaped {} stale request(s)", reaped);
+            continue;
+        }
+        
         // Check if user gesture is required for audio requests
         if !audio_manager.gesture_enabled {
             console_warn!("🎵 Audio request blocked - waiting for user gesture");
@@ -343,10 +622,86 @@ pub fn dispatch_audio_requests(
             continue;
         }
         
-        if let Err(e) = send_audio_request_to_js(request) {
-            console_error!("Failed to send audio request to JS: {:?}", e);
-            audio_manager.handle_error(AudioError::PlaybackFailed(format!("JS dispatch failed: {:?}", e)));
+        if audio_manager.blocked_by_mute(request) {
+            console_log!("🔇 Muted; dropping audio request {}", request.request_id());
+            continue;
+        }
+        
+        // Resolve context requests into a concrete sound from the registry
+        let resolved;
+        let request = if let AudioRequest::PlayContext { request_id, context, volume } = request {
+            let Some(sound_id) = audio_manager.sound_for_context(context) else {
+                console_warn!("🎵 No sound registered for context {:?}", context);
+                audio_manager.handle_error(AudioError::FileNotFound(format!("{:?}", context)));
+                continue;
+            };
+            let volume = audio_manager.effective_volume(&sound_id, *volume).unwrap_or(1.0);
+            resolved = AudioRequest::Play {
+                request_id: request_id.clone(),
+                sound_id,
+                context: context.clone(),
+                volume,
+                loop_audio: false,
+                source_url: None,
+            };
+            &resolved
+        } else {
+            request
+        };
+        
+        let mut request = request.clone();
+        if let AudioRequest::Play { sound_id, source_url, .. } = &mut request {
+            if source_url.is_none() {
+                *source_url = audio_manager.preferred_source(sound_id, browser_can_play);
+            }
+        }
+        
+        match send_audio_request_to_js(&request) {
+            Ok(()) => {
+                let now = js_sys::Date::now();
+                tracker.register(request.request_id(), request.tracker_kind(), now);
+                track_voices(&mut audio_manager, &request, now);
+            }
+            Err(e) => {
+                console_error!("Failed to send audio request to JS: {:?}", e);
+                audio_manager.handle_error(AudioError::PlaybackFailed(format!("JS dispatch failed: {:?}", e)));
+            }
+        }
+    }
+}
+
+/// Keep `playing_sounds` in step with what JS was just asked to do, stopping voices stolen over the cap
+fn track_voices(audio_manager: &mut AudioManager, request: &AudioRequest, now: f64) {
+    match request {
+        AudioRequest::Play { request_id, sound_id, context, volume, loop_audio, .. } => {
+            let voice = PlayingSound {
+                sound_id: sound_id.clone(),
+                context: context.clone(),
+                volume: *volume,
+                started_at: now,
+                is_looping: *loop_audio,
+                ends_at: None,
+            };
+            for (stolen_id, stolen) in audio_manager.register_voice(request_id, voice) {
+                // JS keys elements by sound id; stopping a reused element would cut off the new play
+                if stolen.sound_id == *sound_id {
+                    continue;
+                }
+                console_log!("🔇 Voice limit ({}) reached; stopping {} ({})", audio_manager.max_voices, stolen.sound_id, stolen_id);
+                let stop = AudioRequest::Stop {
+                    request_id: format!("{}-steal", stolen_id),
+                    sound_id: Some(stolen.sound_id),
+                };
+                if let Err(e) = send_audio_request_to_js(&stop) {
+                    console_error!("Failed to stop stolen voice: {:?}", e);
+                }
+            }
+        }
+        AudioRequest::Stop { sound_id: Some(sound_id), .. } => {
+            audio_manager.playing_sounds.retain(|_, voice| &voice.sound_id != sound_id);
         }
+        AudioRequest::Stop { sound_id: None, .. } => audio_manager.playing_sounds.clear(),
+        _ => {}
     }
 }
 
@@ -367,22 +722,48 @@ pub fn handle_user_gesture(
 pub fn handle_audio_responses(
     mut audio_responses: EventReader<AudioResponse>,
     mut audio_manager: ResMut<AudioManager>,
+    mut tracker: ResMut<RequestTracker>,
 ) {
     for response in audio_responses.read() {
+        let (request_id, status) = response.outcome();
+        if !tracker.resolve(request_id, status) && tracker.status(request_id) == Some(&RequestStatus::TimedOut) {
+            console_warn!("🎵 Late audio response for timed-out request {}", request_id);
+            continue; // already counted as a failure when it timed out
+        }
         audio_manager.handle_response(response.clone());
     }
 }
 
+/// Periodically reap pending requests the JS bridge never answered
+pub fn sweep_stale_audio_requests(
+    time: Res<Time>,
+    mut sweep_timer: Local<Timer>,
+    mut audio_manager: ResMut<AudioManager>,
+    mut tracker: ResMut<RequestTracker>,
+) {
+    if sweep_timer.duration().is_zero() {
+        *sweep_timer = Timer::from_seconds(5.0, TimerMode::Repeating);
+    }
+    sweep_timer.tick(time.delta());
+    
+    if sweep_timer.just_finished() && tracker.pending_count("audio") > 0 {
+        let reaped = audio_manager.reap_timed_out(&mut tracker, js_sys::Date::now());
+        if reaped > 0 {
+            console_warn!("🧹 Reaped {} stale pending audio request(s)", reaped);
+        }
+    }
+}
+
 /// Apply shared settings to audio manager (SFX volume, etc.)
 pub fn apply_shared_settings(
     settings: Res<SharedSettings>,
     mut audio_manager: ResMut<AudioManager>,
 ) {
     if settings.is_changed() {
-        audio_manager.global_volume = settings.sfx_volume.clamp(0.0, 1.0);
+        audio_manager.apply_settings(&settings);
         console_log!(
-            "🎚️ Applied shared settings to audio: sfx_volume={}",
-            audio_manager.global_volume
+            "🎚️ Applied shared settings to audio: sfx_volume={} ui={:?} critter={:?} ambient={:?}",
+            settings.sfx_volume, settings.ui_volume, settings.critter_volume, settings.ambient_volume
         );
     }
 }
@@ -396,11 +777,63 @@ pub fn audio_context_system(
     // when leaving an area, trigger exit sound
 }
 
+/// MIME type implied by a source's file extension (None when the extension is unrecognised)
+pub fn mime_for_path(path: &str) -> Option<&'static str> {
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+    match extension.as_str() {
+        "mp3" => Some("audio/mpeg"),
+        "ogg" | "oga" => Some("audio/ogg"),
+        "wav" => Some("audio/wav"),
+        "m4a" | "aac" => Some("audio/mp4"),
+        "webm" => Some("audio/webm"),
+        _ => None,
+    }
+}
+
+/// First source whose format passes `can_play`; unknown formats are assumed playable and,
+/// if nothing matches, the primary source is returned so JS can still attempt it
+pub fn select_playable_source<'a>(sources: &[&'a str], can_play: impl Fn(&str) -> bool) -> Option<&'a str> {
+    sources
+        .iter()
+        .copied()
+        .find(|source| mime_for_path(source).map_or(true, &can_play))
+        .or_else(|| sources.first().copied())
+}
+
+thread_local! {
+    // One probe element for canPlayType, and the browser's answer per MIME type (fixed for the session)
+    static AUDIO_PROBE: Option<web_sys::HtmlAudioElement> = web_sys::HtmlAudioElement::new().ok();
+    static PLAYABLE_MIME_TYPES: std::cell::RefCell<HashMap<String, bool>> = std::cell::RefCell::new(HashMap::new());
+}
+
+/// Look `mime` up in `cache`, asking `probe` only the first time
+fn cached_can_play(cache: &mut HashMap<String, bool>, mime: &str, probe: impl FnOnce(&str) -> bool) -> bool {
+    if let Some(&playable) = cache.get(mime) {
+        return playable;
+    }
+    let playable = probe(mime);
+    cache.insert(mime.to_string(), playable);
+    playable
+}
+
+/// Ask the browser whether it can decode a MIME type ("maybe"/"probably" both count)
+fn browser_can_play(mime: &str) -> bool {
+    PLAYABLE_MIME_TYPES.with(|cache| {
+        cached_can_play(&mut cache.borrow_mut(), mime, |mime| {
+            AUDIO_PROBE.with(|probe| probe.as_ref().is_some_and(|audio| !audio.can_play_type(mime).is_empty()))
+        })
+    })
+}
+
 /// Send audio request to JavaScript via CustomEvent
 fn send_audio_request_to_js(request: &AudioRequest) -> Result<(), JsValue> {
-    let window = web_sys::window().ok_or("No window object")?;
     let request_data = serde_json::to_string(request)
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+    if crate::events::deliver_to_callback("bevy-audio-request", &request_data) {
+        return Ok(());
+    }
+    let window = web_sys::window().ok_or("No window object")?;
     
     let custom_event = CustomEvent::new_with_event_init_dict(
         "bevy-audio-request",
@@ -455,6 +888,7 @@ impl Plugin for PlatformAudioPlugin {
         app
             // Add the audio manager resource
             .init_resource::<AudioManager>()
+            .init_resource::<RequestTracker>()
             
             // Add audio events
             .add_event::<AudioRequest>()
@@ -468,6 +902,7 @@ impl Plugin for PlatformAudioPlugin {
                 handle_audio_responses,
                 audio_context_system,
                 apply_shared_settings,
+                sweep_stale_audio_requests,
             ).chain());
 
         console_log!("🎵 PlatformAudioPlugin initialized");
@@ -483,6 +918,7 @@ pub fn play_enter_sound(mut audio_requests: EventWriter<AudioRequest>) {
         context: AudioContext::Enter,
         volume: 0.8,
         loop_audio: false,
+        source_url: None,
     });
 }
 
@@ -494,6 +930,7 @@ pub fn play_exit_sound(mut audio_requests: EventWriter<AudioRequest>) {
         context: AudioContext::Exit,
         volume: 0.7,
         loop_audio: false,
+        source_url: None,
     });
 }
 
@@ -505,6 +942,7 @@ pub fn test_audio_system(mut audio_requests: EventWriter<AudioRequest>) {
         context: AudioContext::Test,
         volume: 0.8,
         loop_audio: false,
+        source_url: None,
     });
 }
 
@@ -521,6 +959,262 @@ mod tests {
         assert!(audio_manager.sound_registry.contains_key("exit_area"));
     }
     
+    #[test]
+    fn test_voice_limit_steals_oldest_one_shot() {
+        let mut audio_manager = AudioManager { max_voices: 3, ..Default::default() };
+        let voice = |sound_id: &str, started_at: f64, is_looping: bool| PlayingSound {
+            sound_id: sound_id.to_string(),
+            context: AudioContext::Critter,
+            volume: 1.0,
+            started_at,
+            is_looping,
+            ends_at: None,
+        };
+
+        // Background music is exempt and never stolen
+        assert!(audio_manager.register_voice("bgm", voice("music", 0.0, true)).is_empty());
+        for (i, id) in ["a", "b", "c"].iter().enumerate() {
+            assert!(audio_manager.register_voice(id, voice(id, 10.0 + i as f64, false)).is_empty());
+        }
+        assert_eq!(audio_manager.playing_sounds.len(), 4);
+
+        let stolen = audio_manager.register_voice("d", voice("d", 20.0, false));
+        assert_eq!(stolen.len(), 1);
+        assert_eq!(stolen[0].0, "a");
+        assert_eq!(stolen[0].1.sound_id, "a");
+        let mut playing: Vec<_> = audio_manager.playing_sounds.keys().cloned().collect();
+        playing.sort();
+        assert_eq!(playing, vec!["b", "bgm", "c", "d"]);
+
+        // A voice that has finished (per its reported duration) frees its slot instead of stealing one
+        audio_manager.playing_sounds.get_mut("b").unwrap().ends_at = Some(25.0);
+        assert!(audio_manager.register_voice("e", voice("e", 30.0, false)).is_empty());
+        assert!(!audio_manager.playing_sounds.contains_key("b"));
+        assert!(audio_manager.playing_sounds.contains_key("bgm"));
+    }
+
+    #[test]
+    fn test_settings_populate_context_buses() {
+        let mut audio_manager = AudioManager::default();
+        audio_manager.context_volumes.insert(AudioContext::Ambient, 0.4);
+        let settings: SharedSettings = serde_json::from_str(
+            r#"{"music_enabled": false, "bgm_volume": 0.6, "sfx_volume": 0.7, "ui_volume": 0.3, "critter_volume": 0.9}"#,
+        ).unwrap();
+        audio_manager.apply_settings(&settings);
+
+        assert_eq!(audio_manager.global_volume, 0.7);
+        assert_eq!(audio_manager.context_volume(&AudioContext::UI), 0.3);
+        assert_eq!(audio_manager.context_volume(&AudioContext::Critter), 0.9);
+        // Buses the settings don't mention keep their own gain
+        assert_eq!(audio_manager.context_volume(&AudioContext::Ambient), 0.4);
+        assert_eq!(audio_manager.context_volume(&AudioContext::Enter), 1.0);
+        let ui = audio_manager.effective_volume("button_click", None).unwrap();
+        assert!((ui - 0.6 * 0.7 * 0.3).abs() < 1e-6, "{}", ui);
+
+        // Settings without per-context volumes only move the master gain
+        audio_manager.apply_settings(&SharedSettings { sfx_volume: 0.5, ..Default::default() });
+        assert_eq!(audio_manager.global_volume, 0.5);
+        assert_eq!(audio_manager.context_volume(&AudioContext::UI), 0.3);
+        assert_eq!(audio_manager.context_volume(&AudioContext::Ambient), 0.4);
+    }
+
+    #[test]
+    fn test_settings_critter_volume_reaches_catalog_sounds() {
+        use crate::systems::{catalog_sound_system, critter_interaction_system, critter_spawning_system, played_catalog_sounds};
+
+        let mut game = crate::test_support::TestGame::new();
+        game.app.add_systems(Update, (
+            apply_shared_settings.before(critter_spawning_system),
+            catalog_sound_system.after(critter_interaction_system),
+        ));
+        let entry = "audio/test/settings_entry.ogg";
+        game.app
+            .init_resource::<AudioManager>()
+            .insert_resource(AudioGate { enabled: true, ..Default::default() })
+            .insert_resource(SharedSettings { sfx_volume: 0.8, critter_volume: Some(0.5), ..Default::default() })
+            .insert_resource(crate::resources::CritterSounds {
+                sounds: HashMap::from([("bouncy_bunny".to_string(), crate::resources::CritterSoundSet {
+                    entry: entry.to_string(),
+                    success: vec!["audio/test/settings_success.ogg".to_string()],
+                    success_weights: Vec::new(),
+                })]),
+            });
+        let mut config = game.app.world_mut().resource_mut::<crate::resources::GameConfig>();
+        config.entry_sound_after_spawn_in = false;
+        config.max_critters = 5;
+
+        // The settings panel's critter slider, on top of sfx_volume, is what a critter's entry sound plays at
+        game.spawn("bouncy_bunny");
+        game.app.world_mut().resource_mut::<SharedSettings>().critter_volume = Some(0.25);
+        game.spawn("bouncy_bunny");
+        // Settings without a critter volume leave the bus alone; sfx_volume still scales it
+        game.app.insert_resource(SharedSettings { sfx_volume: 0.4, ..Default::default() });
+        game.spawn("bouncy_bunny");
+        let played = played_catalog_sounds(entry);
+        let expected = [0.8 * 0.5, 0.8 * 0.25, 0.4 * 0.25];
+        assert_eq!(played.len(), expected.len(), "{:?}", played);
+        for (played, expected) in played.iter().zip(expected) {
+            assert!((played - expected).abs() < 1e-6, "{} vs {}", played, expected);
+        }
+    }
+
+    #[test]
+    fn test_playable_mime_types_are_probed_once() {
+        let mut cache = HashMap::new();
+        let probes = std::cell::Cell::new(0);
+        let probe = |mime: &str| {
+            probes.set(probes.get() + 1);
+            mime == "audio/ogg"
+        };
+        for _ in 0..3 {
+            assert!(cached_can_play(&mut cache, "audio/ogg", probe));
+            assert!(!cached_can_play(&mut cache, "audio/x-caf", probe));
+        }
+        assert_eq!(probes.get(), 2, "one probe per MIME type");
+    }
+
+    #[test]
+    fn test_mute_silences_and_restores_volumes() {
+        let mut audio_manager = AudioManager::default();
+        audio_manager.global_volume = 0.8;
+        audio_manager.context_volumes.insert(AudioContext::UI, 0.5);
+        let voice = |sound_id: &str, is_looping: bool| PlayingSound {
+            sound_id: sound_id.to_string(),
+            context: AudioContext::Ambient,
+            volume: 1.0,
+            started_at: 0.0,
+            is_looping,
+            ends_at: None,
+        };
+        audio_manager.register_voice("bgm", voice("music", true));
+        audio_manager.register_voice("pop", voice("pop", false));
+        let play = AudioRequest::Play {
+            request_id: "p1".to_string(),
+            sound_id: "button_click".to_string(),
+            context: AudioContext::UI,
+            volume: 0.6,
+            loop_audio: false,
+            source_url: None,
+        };
+        let before = audio_manager.effective_volume("button_click", None).unwrap();
+        assert!(!audio_manager.blocked_by_mute(&play));
+
+        // Muting pauses the loop, cuts the one-shot and drops new plays
+        let follow_ups = audio_manager.set_muted(true, "m1");
+        assert!(matches!(&follow_ups[..], [
+            AudioRequest::Pause { sound_id: looped, .. },
+            AudioRequest::Stop { sound_id: Some(one_shot), .. },
+        ] if looped == "music" && one_shot == "pop"), "{:?}", follow_ups);
+        assert!(audio_manager.blocked_by_mute(&play));
+        assert_eq!(audio_manager.effective_volume("button_click", None), Some(0.0));
+        // Muting twice is a no-op
+        assert!(audio_manager.set_muted(true, "m2").is_empty());
+
+        // Unmuting resumes the loop with every volume as it was
+        let follow_ups = audio_manager.set_muted(false, "m3");
+        assert!(matches!(&follow_ups[..], [AudioRequest::Resume { sound_id, .. }] if sound_id == "music"), "{:?}", follow_ups);
+        assert!(!audio_manager.blocked_by_mute(&play));
+        assert_eq!(audio_manager.global_volume, 0.8);
+        assert_eq!(audio_manager.context_volume(&AudioContext::UI), 0.5);
+        assert_eq!(audio_manager.effective_volume("button_click", None), Some(before));
+    }
+
+    #[test]
+    fn test_context_volume_bus() {
+        let mut audio_manager = AudioManager::default();
+        audio_manager.sound_registry.insert("chirp".to_string(), AudioFileInfo {
+            file_path: "assets/audio/critters/chirp.ogg".to_string(),
+            context: AudioContext::Critter,
+            default_volume: 0.8,
+            format: AudioFormat::Ogg,
+            alternatives: Vec::new(),
+        });
+        audio_manager.context_volumes.insert(AudioContext::Critter, 0.5);
+        
+        let critter = audio_manager.effective_volume("chirp", None).unwrap();
+        assert!((critter - 0.4).abs() < 1e-6);
+        // UI bus is unaffected
+        let ui = audio_manager.effective_volume("button_click", None).unwrap();
+        assert!((ui - 0.6).abs() < 1e-6);
+        assert_eq!(AudioContext::from_name("Critter"), Some(AudioContext::Critter));
+        assert_eq!(AudioContext::from_name("nope"), None);
+    }
+    
+    #[test]
+    fn test_reap_stale_pending_requests() {
+        let mut audio_manager = AudioManager::default();
+        let mut tracker = RequestTracker::default();
+        tracker.register("stale", "audio:play", 1_000.0);
+        tracker.register("fresh", "audio:play", 40_000.0);
+        audio_manager.playing_sounds.insert("stale".to_string(), PlayingSound {
+            sound_id: "yipee".to_string(),
+            context: AudioContext::Test,
+            volume: 0.8,
+            started_at: 1_000.0,
+            is_looping: false,
+            ends_at: None,
+        });
+        
+        let reaped = audio_manager.reap_timed_out(&mut tracker, 45_000.0);
+        assert_eq!(reaped, 1);
+        assert!(!audio_manager.playing_sounds.contains_key("stale"), "a voice JS never confirmed is dropped");
+        assert_eq!(tracker.pending_count("audio"), 1, "the fresh request is still pending");
+        assert_eq!(audio_manager.error_count, 1);
+    }
+    
+    #[test]
+    fn test_reap_timed_out_tracked_requests() {
+        let mut audio_manager = AudioManager::default();
+        let mut tracker = RequestTracker::default();
+        let play = AudioRequest::Test { request_id: "t1".to_string(), test_type: "ping".to_string() };
+        tracker.register(play.request_id(), play.tracker_kind(), 1_000.0);
+        tracker.register("t2", "audio:test", 1_000.0);
+        tracker.resolve("t2", AudioResponse::TestCompleted { request_id: "t2".to_string(), result: "ok".to_string() }.outcome().1);
+        
+        assert_eq!(audio_manager.reap_timed_out(&mut tracker, 45_000.0), 1);
+        assert_eq!(tracker.status("t1"), Some(&RequestStatus::TimedOut));
+        assert_eq!(audio_manager.error_count, 1);
+    }
+    
+    #[test]
+    fn test_sound_for_context() {
+        let audio_manager = AudioManager::default();
+        assert_eq!(audio_manager.sound_for_context(&AudioContext::Enter).as_deref(), Some("enter_area"));
+        assert_eq!(audio_manager.sound_for_context(&AudioContext::Exit).as_deref(), Some("exit_area"));
+        assert_eq!(audio_manager.sound_for_context(&AudioContext::UI).as_deref(), Some("button_click"));
+        assert_eq!(audio_manager.sound_for_context(&AudioContext::Test).as_deref(), Some("yipee"));
+        assert_eq!(audio_manager.sound_for_context(&AudioContext::Ambient), None);
+        // Invalid context names are rejected at the boundary
+        assert!(AudioContext::from_name("hallway").is_none());
+    }
+    
+    #[test]
+    fn test_preferred_source_respects_platform_formats() {
+        let mut audio_manager = AudioManager::default();
+        audio_manager.sound_registry.insert("bark".to_string(), AudioFileInfo {
+            file_path: "assets/audio/critters/bark.ogg".to_string(),
+            context: AudioContext::Critter,
+            default_volume: 0.8,
+            format: AudioFormat::Ogg,
+            alternatives: vec!["assets/audio/critters/bark.m4a".to_string(), "assets/audio/critters/bark.mp3".to_string()],
+        });
+        // Safari-like capability set: no Ogg Vorbis
+        let safari = |mime: &str| matches!(mime, "audio/mpeg" | "audio/mp4" | "audio/wav");
+        let everything = |_: &str| true;
+        let nothing = |_: &str| false;
+        
+        assert_eq!(audio_manager.preferred_source("bark", safari).as_deref(), Some("assets/audio/critters/bark.m4a"));
+        assert_eq!(audio_manager.preferred_source("bark", everything).as_deref(), Some("assets/audio/critters/bark.ogg"));
+        // Nothing decodable: keep the primary path so JS can still try its own fallbacks
+        assert_eq!(audio_manager.preferred_source("bark", nothing).as_deref(), Some("assets/audio/critters/bark.ogg"));
+        // Sounds without alternatives keep their single path
+        assert_eq!(audio_manager.preferred_source("enter_area", safari).as_deref(), Some("assets/audio/ui/enter_chime.mp3"));
+        assert!(audio_manager.preferred_source("missing", everything).is_none());
+        
+        assert_eq!(mime_for_path("https://cdn.example/a/b.MP3?v=2"), Some("audio/mpeg"));
+        assert_eq!(select_playable_source(&["clip.flac", "clip.mp3"], nothing), Some("clip.flac"));
+    }
+    
     #[test]
     fn test_audio_request_serialization() {
         let request = AudioRequest::Play {
@@ -529,6 +1223,7 @@ mod tests {
             context: AudioContext::Test,
             volume: 0.8,
             loop_audio: false,
+            source_url: None,
         };
         
         let serialized = serde_json::to_string(&request).unwrap();
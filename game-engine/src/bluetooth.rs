@@ -3,7 +3,53 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 use web_sys::console;
-use js_sys;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Routine traffic line, written only when the manager's verbose logging is on
+macro_rules! bt_log {
+    ($bt:expr, $($arg:tt)*) => {
+        if $bt.log_enabled(false) {
+            bluetooth_log(format!($($arg)*));
+        }
+    };
+}
+
+/// Write one Bluetooth log line: the browser console on wasm, a capture buffer in native tests
+pub fn bluetooth_log(line: String) {
+    #[cfg(target_arch = "wasm32")]
+    console::log_1(&line.into());
+    #[cfg(all(test, not(target_arch = "wasm32")))]
+    if let Ok(mut lines) = BLUETOOTH_LOG_LINES.lock() {
+        lines.push(line);
+    }
+    #[cfg(all(not(test), not(target_arch = "wasm32")))]
+    let _ = line;
+}
+
+/// Lines native tests "logged", since they have no console
+#[cfg(all(test, not(target_arch = "wasm32")))]
+static BLUETOOTH_LOG_LINES: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+/// Logged lines mentioning `needle`, in order
+#[cfg(all(test, not(target_arch = "wasm32")))]
+fn bluetooth_log_lines(needle: &str) -> Vec<String> {
+    BLUETOOTH_LOG_LINES.lock().map(|lines| {
+        lines.iter().filter(|line| line.contains(needle)).cloned().collect()
+    }).unwrap_or_default()
+}
+
+/// Wall-clock ms (js Date::now) for cache and command-log timestamps; native builds have no JS clock and read 0
+fn wall_clock_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0.0
+    }
+}
 
 /// Component to mark entities that should explode when despawned
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -38,6 +84,52 @@ pub enum BluetoothLEDeviceType {
     Unknown { service_uuid: String },
 }
 
+/// Device family used to group devices in the UI (collars vs feeders, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeviceCategory {
+    SmartCollar,
+    FeedingStation,
+    ToyDispenser,
+    ActivityTracker,
+    TestDevice,
+    Unknown,
+}
+
+impl DeviceCategory {
+    /// Parse a category name from JS; case, '_' and '-' are ignored ("SmartCollar", "smart_collar")
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let normalized: String = name
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match normalized.as_str() {
+            "smartcollar" => Ok(DeviceCategory::SmartCollar),
+            "feedingstation" => Ok(DeviceCategory::FeedingStation),
+            "toydispenser" => Ok(DeviceCategory::ToyDispenser),
+            "activitytracker" => Ok(DeviceCategory::ActivityTracker),
+            "testdevice" => Ok(DeviceCategory::TestDevice),
+            "unknown" => Ok(DeviceCategory::Unknown),
+            _ => Err(format!("Unknown device type: '{}'", name)),
+        }
+    }
+}
+
+impl BluetoothLEDeviceType {
+    /// Category of this device; virtual devices report the type they emulate
+    pub fn category(&self) -> DeviceCategory {
+        match self {
+            BluetoothLEDeviceType::SmartCollar { .. } => DeviceCategory::SmartCollar,
+            BluetoothLEDeviceType::FeedingStation { .. } => DeviceCategory::FeedingStation,
+            BluetoothLEDeviceType::ToyDispenser { .. } => DeviceCategory::ToyDispenser,
+            BluetoothLEDeviceType::ActivityTracker { .. } => DeviceCategory::ActivityTracker,
+            BluetoothLEDeviceType::VirtualDevice { emulated_type } => emulated_type.category(),
+            BluetoothLEDeviceType::TestDevice { .. } => DeviceCategory::TestDevice,
+            BluetoothLEDeviceType::Unknown { .. } => DeviceCategory::Unknown,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CollarType {
     TrainingCollar,
@@ -85,8 +177,28 @@ pub struct BluetoothLEManager {
     pub virtual_network_enabled: bool,
     pub virtual_devices: HashMap<DeviceId, VirtualDevice>,
     pub virtual_command_log: Vec<VirtualCommand>,
+    pub virtual_rng: StdRng, // sensor noise; kept off GameRng so Bluetooth traffic can't shift a gameplay replay
+    
+    // Last-known info per device (survives scan sessions) so reconnects skip rediscovery
+    pub known_devices: HashMap<DeviceId, CachedDeviceInfo>,
+    pub device_cache_ttl_ms: f64,
+    
+    // Scripted scenario being played back against the virtual network
+    pub active_scenario: Option<ScenarioRun>,
+    
+    // Log routine request/response traffic too (errors are always logged)
+    pub verbose_logging: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct CachedDeviceInfo {
+    pub info: DeviceInfo,
+    pub cached_at: f64, // ms timestamp (js Date::now)
+}
+
+/// Cached device info older than this is treated as unknown (24h)
+pub const DEFAULT_DEVICE_CACHE_TTL_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VirtualDevice {
     pub info: DeviceInfo,
@@ -95,6 +207,24 @@ pub struct VirtualDevice {
     pub auto_responses: bool,
 }
 
+impl VirtualDevice {
+    /// Raw GATT write: keep the bytes (as hex) in `state` under the characteristic uuid and echo them back
+    pub fn write_raw(&mut self, service_uuid: &str, characteristic_uuid: &str, data: &[u8]) -> ZephyrResponse {
+        let data_hex = hex_string(data);
+        self.state.insert(characteristic_uuid.to_string(), serde_json::Value::String(data_hex.clone()));
+        ZephyrResponse::RawData {
+            service_uuid: service_uuid.to_string(),
+            characteristic_uuid: characteristic_uuid.to_string(),
+            data_hex,
+        }
+    }
+}
+
+/// Lowercase hex encoding of raw bytes ("0a ff" -> "0aff")
+pub fn hex_string(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VirtualCommandHandler {
     pub command_pattern: String,
@@ -102,6 +232,66 @@ pub struct VirtualCommandHandler {
     pub delay_ms: u64,
 }
 
+/// One scripted request, issued `at_ms` after the scenario starts
+#[derive(Debug, Clone)]
+pub struct ScenarioStep {
+    pub at_ms: u32,
+    pub request: BluetoothLERequest,
+}
+
+/// A named device interaction script for frontend integration tests
+#[derive(Debug, Clone)]
+pub struct VirtualScenario {
+    pub name: String,
+    pub devices: Vec<VirtualDevice>,
+    pub steps: Vec<ScenarioStep>, // sorted by at_ms
+}
+
+/// Playback state of a running scenario
+#[derive(Debug, Clone)]
+pub struct ScenarioRun {
+    pub scenario: VirtualScenario,
+    pub elapsed_ms: f64,
+    pub next_step: usize,
+    pub devices_registered: bool,
+}
+
+/// Names accepted by `builtin_scenario`
+pub const BUILTIN_SCENARIOS: &[&str] = &["collar-pairing", "feeder-session"];
+
+/// Built-in scenario by name: scan, discover, connect and exchange a couple of commands with one device
+pub fn builtin_scenario(name: &str) -> Option<VirtualScenario> {
+    let (device_id, commands) = match name {
+        "collar-pairing" => ("virtual_collar_001", vec![
+            ZephyrCommand::GetBatteryLevel,
+            ZephyrCommand::CollarCommands { command: CollarCommand::Vibrate { intensity: 40, duration_ms: 300 } },
+        ]),
+        "feeder-session" => ("virtual_feeder_001", vec![
+            ZephyrCommand::GetDeviceInfo,
+            ZephyrCommand::FeedingCommands { command: FeedingCommand::DispenseFood { amount_grams: 25 } },
+        ]),
+        _ => return None,
+    };
+    let device_id = DeviceId(device_id.to_string());
+    let devices: Vec<VirtualDevice> = virtual_devices_seen_at(None)
+        .into_iter()
+        .filter(|device| device.info.id == device_id)
+        .collect();
+
+    let mut steps = vec![
+        ScenarioStep { at_ms: 0, request: BluetoothLERequest::StartScan { duration_ms: Some(1500), device_filter: None } },
+        ScenarioStep { at_ms: 1500, request: BluetoothLERequest::StopScan },
+        ScenarioStep { at_ms: 1800, request: BluetoothLERequest::Connect { device_id: device_id.clone() } },
+    ];
+    for (i, command) in commands.into_iter().enumerate() {
+        steps.push(ScenarioStep {
+            at_ms: 2300 + 500 * i as u32,
+            request: BluetoothLERequest::SendCommand { device_id: device_id.clone(), command, timeout_ms: Some(5000) },
+        });
+    }
+    Some(VirtualScenario { name: name.to_string(), devices, steps })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VirtualCommand {
     pub timestamp: f64,
@@ -165,6 +355,10 @@ pub enum BluetoothLERequest {
     RegisterVirtualDevice { device: VirtualDevice },
     RemoveVirtualDevice { device_id: DeviceId },
     SimulateDeviceCommand { device_id: DeviceId, command: String },
+    RunVirtualScenario { name: String },
+    
+    // Diagnostics
+    SetVerboseLogging { enabled: bool },
 }
 
 #[derive(Event, Debug, Clone, Serialize, Deserialize)]
@@ -197,6 +391,8 @@ pub enum BluetoothLEResponse {
     VirtualNetworkEnabled,
     VirtualNetworkDisabled,
     VirtualDeviceRegistered { device_id: DeviceId },
+    ScenarioStarted { name: String },
+    ScenarioCompleted { name: String },
     VirtualCommandExecuted { 
         device_id: DeviceId, 
         command: String, 
@@ -207,6 +403,16 @@ pub enum BluetoothLEResponse {
     Error { error: BluetoothLEError },
 }
 
+impl BluetoothLEResponse {
+    /// Failures that are logged even with verbose Bluetooth logging off
+    pub fn is_error(&self) -> bool {
+        matches!(self,
+            BluetoothLEResponse::PairingFailed { .. }
+                | BluetoothLEResponse::CommandFailed { .. }
+                | BluetoothLEResponse::Error { .. })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BluetoothLEDeviceFilter {
     pub device_types: Option<Vec<BluetoothLEDeviceType>>,
@@ -248,6 +454,21 @@ pub enum ZephyrCommand {
     },
 }
 
+impl ZephyrCommand {
+    /// Collar vibration with its intensity scaled (FeedbackIntensity::vibration_scale); other commands pass through
+    pub fn with_vibration_scale(&self, scale: f32) -> ZephyrCommand {
+        match self {
+            ZephyrCommand::CollarCommands { command: CollarCommand::Vibrate { intensity, duration_ms } } => ZephyrCommand::CollarCommands {
+                command: CollarCommand::Vibrate {
+                    intensity: (*intensity as f32 * scale.clamp(0.0, 1.0)).round() as u8,
+                    duration_ms: *duration_ms,
+                },
+            },
+            other => other.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CollarCommand {
     Vibrate { intensity: u8, duration_ms: u32 },
@@ -307,6 +528,11 @@ pub enum ZephyrResponse {
         timestamp: u64 
     },
     FoodLevel { current_grams: u32, capacity_grams: u32 },
+    RawData { 
+        service_uuid: String, 
+        characteristic_uuid: String, 
+        data_hex: String // lowercase hex, two digits per byte
+    },
     Success,
     Error { code: u16, message: String },
 }
@@ -325,6 +551,11 @@ impl Default for BluetoothLEManager {
             virtual_network_enabled: false,
             virtual_devices: HashMap::new(),
             virtual_command_log: Vec::new(),
+            virtual_rng: StdRng::seed_from_u64(VIRTUAL_NETWORK_SEED),
+            known_devices: HashMap::new(),
+            device_cache_ttl_ms: DEFAULT_DEVICE_CACHE_TTL_MS,
+            active_scenario: None,
+            verbose_logging: false,
         }
     }
 }
@@ -332,7 +563,7 @@ impl Default for BluetoothLEManager {
 impl BluetoothLEManager {
     /// Handle errors following b00t pattern
     pub fn handle_error(&mut self, error: BluetoothLEError) {
-        console::log_1(&format!("🔵 BluetoothLE error: {}", error).into());
+        bluetooth_log(format!("🔵 BluetoothLE error: {}", error));
         
         self.last_error = Some(error.clone());
         self.error_count += 1;
@@ -343,15 +574,57 @@ impl BluetoothLEManager {
         );
     }
     
+    /// Whether a log line should be written under the current verbosity
+    pub fn log_enabled(&self, is_error: bool) -> bool {
+        is_error || self.verbose_logging
+    }
+    
     pub fn should_retry(&self) -> bool {
         self.error_count < 3 && 
         self.retry_backoff.as_millis() < 30000 // Max 30s backoff
     }
     
+    /// Connected and discovered devices, one entry per id (connected info wins), sorted by id
+    pub fn all_devices(&self) -> Vec<DeviceInfo> {
+        let mut devices: HashMap<&DeviceId, &DeviceInfo> = self.discovered_devices.iter().collect();
+        devices.extend(self.connected_devices.iter());
+        let mut devices: Vec<DeviceInfo> = devices.into_values().cloned().collect();
+        devices.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+        devices
+    }
+    
+    /// Record a discovered device, refreshing its cache entry
+    pub fn note_discovered(&mut self, info: DeviceInfo, now: f64) {
+        self.remember_device(&info, now);
+        self.discovered_devices.insert(info.id.clone(), info);
+    }
+    
+    pub fn remember_device(&mut self, info: &DeviceInfo, now: f64) {
+        self.known_devices.insert(info.id.clone(), CachedDeviceInfo { info: info.clone(), cached_at: now });
+    }
+    
+    /// Last-known info for a device, unless the cache entry has outlived the TTL
+    pub fn cached_device(&self, device_id: &DeviceId, now: f64) -> Option<&DeviceInfo> {
+        self.known_devices
+            .get(device_id)
+            .filter(|cached| now - cached.cached_at <= self.device_cache_ttl_ms)
+            .map(|cached| &cached.info)
+    }
+    
+    /// On reconnect, fill `connected_devices` from the cache right away; expired entries are dropped
+    pub fn reconnect_from_cache(&mut self, device_id: &DeviceId, now: f64) -> bool {
+        let Some(info) = self.cached_device(device_id, now).cloned() else {
+            self.known_devices.remove(device_id);
+            return false;
+        };
+        self.connected_devices.insert(device_id.clone(), DeviceInfo { is_connected: true, ..info });
+        true
+    }
+    
     /// Register a virtual device for testing
     pub fn register_virtual_device(&mut self, device: VirtualDevice) {
         let device_id = device.info.id.clone();
-        console::log_1(&format!("🔵 Registering virtual device: {:?}", device_id).into());
+        bt_log!(self, "🔵 Registering virtual device: {:?}", device_id);
         
         self.virtual_devices.insert(device_id.clone(), device.clone());
         self.discovered_devices.insert(device_id, device.info);
@@ -362,7 +635,7 @@ impl BluetoothLEManager {
         if let Some(virtual_device) = self.virtual_devices.get_mut(device_id) {
             // Log the command
             self.virtual_command_log.push(VirtualCommand {
-                timestamp: js_sys::Date::now(),
+                timestamp: wall_clock_ms(),
                 device_id: device_id.clone(),
                 command: command.to_string(),
                 response: None,
@@ -378,7 +651,7 @@ impl BluetoothLEManager {
                         last_cmd.response = Some(response.clone());
                     }
                     
-                    console::log_1(&format!("🔵 Virtual device {} responded: {}", device_id.0, response).into());
+                    bt_log!(self, "🔵 Virtual device {} responded: {}", device_id.0, response);
                     return Some(response);
                 }
             }
@@ -391,6 +664,15 @@ impl BluetoothLEManager {
     }
 }
 
+/// Devices from `devices` belonging to one category
+pub fn devices_in_category(devices: &[DeviceInfo], category: DeviceCategory) -> Vec<DeviceInfo> {
+    devices
+        .iter()
+        .filter(|device| device.device_type.category() == category)
+        .cloned()
+        .collect()
+}
+
 /// BluetoothLE Plugin following b00t pattern
 pub struct BluetoothLEPlugin;
 
@@ -404,23 +686,99 @@ impl Plugin for BluetoothLEPlugin {
             .add_event::<BluetoothLEResponse>()
             .add_systems(Update, (
                 handle_bluetoothle_requests,
+                poll_bluetooth_responses,
                 process_bluetoothle_responses,
                 bluetoothle_connection_monitor,
                 virtual_network_system,
+                virtual_scenario_system.before(handle_bluetoothle_requests),
             ));
         
         console::log_1(&"🔵 BluetoothLEPlugin setup complete!".into());
     }
 }
 
+/// Inject a JSON-encoded BluetoothLEResponse from JS (lets the frontend simulate device events)
+pub fn send_bluetooth_response_to_bevy(response_json: &str) -> Result<(), wasm_bindgen::JsValue> {
+    let response: BluetoothLEResponse = serde_json::from_str(response_json)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&format!("Failed to parse bluetooth response: {}", e)))?;
+
+    crate::lock_queue(&crate::BLUETOOTH_RESPONSE_QUEUE).push_back(response);
+
+    Ok(())
+}
+
+/// Forward JS-injected responses into the regular response event stream
+fn poll_bluetooth_responses(mut responses: EventWriter<BluetoothLEResponse>) {
+    for response in crate::lock_queue(&crate::BLUETOOTH_RESPONSE_QUEUE).drain(..) {
+        responses.write(response);
+    }
+}
+
+/// Plausible synthetic reading for a dog-worn sensor
+pub fn virtual_sensor_reading(sensor: &SensorType, rng: &mut impl Rng) -> Vec<f32> {
+    match sensor {
+        SensorType::HeartRate => vec![rng.gen_range(70.0..90.0)], // bpm, resting dog
+        SensorType::Temperature => vec![rng.gen_range(37.8..39.0)], // °C, normal canine range
+        SensorType::Accelerometer => vec![
+            rng.gen_range(-0.3..0.3),
+            rng.gen_range(-0.3..0.3),
+            1.0 + rng.gen_range(-0.1..0.1), // g, gravity on z
+        ],
+        SensorType::Gyroscope => (0..3).map(|_| rng.gen_range(-15.0..15.0)).collect(), // deg/s
+        SensorType::GPS => vec![
+            52.52 + rng.gen_range(-0.001..0.001),
+            13.405 + rng.gen_range(-0.001..0.001),
+            rng.gen_range(3.0..8.0), // accuracy in meters
+        ],
+        SensorType::Microphone => vec![rng.gen_range(35.0..70.0)], // dB
+    }
+}
+
+/// Simulated Zephyr firmware reply for a command sent to a virtual device
+pub fn virtual_zephyr_response(command: &ZephyrCommand, rng: &mut impl Rng, timestamp: u64) -> ZephyrResponse {
+    match command {
+        ZephyrCommand::GetBatteryLevel => {
+            ZephyrResponse::BatteryLevel { percentage: 85, voltage_mv: 3700 }
+        },
+        ZephyrCommand::GetDeviceInfo => {
+            ZephyrResponse::DeviceInfo {
+                firmware_version: "1.0.0".to_string(),
+                hardware_version: "v2.1".to_string(),
+                serial_number: "VRT001".to_string(),
+            }
+        },
+        ZephyrCommand::TrackerCommands { command: TrackerCommand::GetSensorData { sensor } } => {
+            ZephyrResponse::SensorData {
+                sensor: sensor.clone(),
+                values: virtual_sensor_reading(sensor, rng),
+                timestamp,
+            }
+        },
+        ZephyrCommand::RawCommand { service_uuid, characteristic_uuid, data } => {
+            ZephyrResponse::RawData {
+                service_uuid: service_uuid.clone(),
+                characteristic_uuid: characteristic_uuid.clone(),
+                data_hex: hex_string(data),
+            }
+        },
+        _ => ZephyrResponse::Success,
+    }
+}
+
+/// Seed for the virtual network's sensor noise, restored each time the network is enabled so simulated sessions repeat
+pub const VIRTUAL_NETWORK_SEED: u64 = 0xB1E_5EED;
+
 /// Handle BluetoothLE requests from game logic
 fn handle_bluetoothle_requests(
     mut bt: ResMut<BluetoothLEManager>,
     mut requests: EventReader<BluetoothLERequest>,
     mut responses: EventWriter<BluetoothLEResponse>,
+    feedback: Option<Res<crate::resources::FeedbackIntensity>>,
 ) {
+    let bt = &mut *bt;
+    let vibration_scale = feedback.map_or(1.0, |level| level.vibration_scale());
     for request in requests.read() {
-        console::log_1(&format!("🔵 Processing BluetoothLE request: {:?}", request).into());
+        bt_log!(bt, "🔵 Processing BluetoothLE request: {:?}", request);
         
         match request {
             BluetoothLERequest::StartScan { duration_ms, device_filter } => {
@@ -452,33 +810,34 @@ fn handle_bluetoothle_requests(
                     // Clone device info to avoid borrowing issues
                     let device_info = bt.virtual_devices.get(device_id).map(|d| d.info.clone());
                     if let Some(info) = device_info {
+                        bt.remember_device(&info, wall_clock_ms());
                         bt.connected_devices.insert(device_id.clone(), info);
                     }
                     responses.write(BluetoothLEResponse::Connected { device_id: device_id.clone() });
                 } else {
                     // Real device connection would be handled by TypeScript bridge
-                    console::log_1(&format!("🔵 Real device connection requested: {:?}", device_id).into());
+                    if bt.reconnect_from_cache(device_id, wall_clock_ms()) {
+                        bt_log!(bt, "🔵 Reusing cached info for reconnect: {:?}", device_id);
+                    }
+                    bt_log!(bt, "🔵 Real device connection requested: {:?}", device_id);
                 }
             },
             
             BluetoothLERequest::SendCommand { device_id, command, timeout_ms } => {
+                // Collar buzzes follow the catch feedback setting, like particles and volume do
+                let command = &command.with_vibration_scale(vibration_scale);
                 if bt.virtual_network_enabled {
                     // Handle virtual device command
                     let command_str = format!("{:?}", command);
                     if let Some(response) = bt.execute_virtual_command(device_id, &command_str) {
-                        // Simulate Zephyr response
-                        let zephyr_response = match command {
-                            ZephyrCommand::GetBatteryLevel => {
-                                ZephyrResponse::BatteryLevel { percentage: 85, voltage_mv: 3700 }
-                            },
-                            ZephyrCommand::GetDeviceInfo => {
-                                ZephyrResponse::DeviceInfo {
-                                    firmware_version: "1.0.0".to_string(),
-                                    hardware_version: "v2.1".to_string(),
-                                    serial_number: "VRT001".to_string(),
-                                }
+                        // Simulate Zephyr response (sensor noise comes from the virtual network's own seeded RNG)
+                        let now = wall_clock_ms() as u64;
+                        let zephyr_response = match (command, bt.virtual_devices.get_mut(device_id)) {
+                            // Raw writes also land in the device state so later reads see them
+                            (ZephyrCommand::RawCommand { service_uuid, characteristic_uuid, data }, Some(device)) => {
+                                device.write_raw(service_uuid, characteristic_uuid, data)
                             },
-                            _ => ZephyrResponse::Success,
+                            _ => virtual_zephyr_response(command, &mut bt.virtual_rng, now),
                         };
                         
                         responses.write(BluetoothLEResponse::CommandResponse {
@@ -489,14 +848,15 @@ fn handle_bluetoothle_requests(
                         });
                     }
                 } else {
-                    console::log_1(&format!("🔵 Real device command: {:?} -> {:?}", device_id, command).into());
+                    bt_log!(bt, "🔵 Real device command: {:?} -> {:?}", device_id, command);
                 }
             },
             
             BluetoothLERequest::EnableVirtualNetwork => {
                 bt.virtual_network_enabled = true;
+                bt.virtual_rng = StdRng::seed_from_u64(VIRTUAL_NETWORK_SEED);
                 responses.write(BluetoothLEResponse::VirtualNetworkEnabled);
-                console::log_1(&"🔵 Virtual BluetoothLE network enabled".into());
+                bt_log!(bt, "🔵 Virtual BluetoothLE network enabled");
             },
             
             BluetoothLERequest::RegisterVirtualDevice { device } => {
@@ -505,19 +865,51 @@ fn handle_bluetoothle_requests(
                 responses.write(BluetoothLEResponse::VirtualDeviceRegistered { device_id });
             },
             
+            BluetoothLERequest::RunVirtualScenario { name } => {
+                match builtin_scenario(name) {
+                    Some(scenario) => {
+                        bt_log!(bt, "🔵 Running virtual scenario '{}' ({} steps)", name, scenario.steps.len());
+                        bt.active_scenario = Some(ScenarioRun {
+                            scenario,
+                            elapsed_ms: 0.0,
+                            next_step: 0,
+                            devices_registered: false,
+                        });
+                        responses.write(BluetoothLEResponse::ScenarioStarted { name: name.clone() });
+                    }
+                    None => {
+                        responses.write(BluetoothLEResponse::Error {
+                            error: BluetoothLEError::PlatformError {
+                                message: format!("Unknown virtual scenario '{}'", name),
+                            },
+                        });
+                    }
+                }
+            },
+            
+            BluetoothLERequest::SetVerboseLogging { enabled } => {
+                bt.verbose_logging = *enabled;
+            },
+            
             _ => {
-                console::log_1(&format!("🔵 Unhandled BluetoothLE request: {:?}", request).into());
+                bt_log!(bt, "🔵 Unhandled BluetoothLE request: {:?}", request);
             }
         }
     }
 }
 
-/// Process BluetoothLE responses (placeholder for future expansion)
+/// Process BluetoothLE responses; discoveries feed the device-info cache
 fn process_bluetoothle_responses(
+    mut bt: ResMut<BluetoothLEManager>,
     mut responses: EventReader<BluetoothLEResponse>,
 ) {
     for response in responses.read() {
-        console::log_1(&format!("🔵 BluetoothLE response: {:?}", response).into());
+        if bt.log_enabled(response.is_error()) {
+            bluetooth_log(format!("🔵 BluetoothLE response: {:?}", response));
+        }
+        if let BluetoothLEResponse::DeviceDiscovered { device } = response {
+            bt.note_discovered(device.clone(), wall_clock_ms());
+        }
     }
 }
 
@@ -539,8 +931,48 @@ fn virtual_network_system(
     }
 }
 
+/// Play back the active scenario: enable the virtual network and register its devices, then issue each
+/// step once its time comes. Completion is reported a frame after the last step so its responses come first.
+fn virtual_scenario_system(
+    time: Res<Time>,
+    mut bt: ResMut<BluetoothLEManager>,
+    mut requests: EventWriter<BluetoothLERequest>,
+    mut responses: EventWriter<BluetoothLEResponse>,
+) {
+    let Some(run) = bt.active_scenario.as_mut() else { return };
+    
+    if !run.devices_registered {
+        run.devices_registered = true;
+        requests.write(BluetoothLERequest::EnableVirtualNetwork);
+        for device in &run.scenario.devices {
+            requests.write(BluetoothLERequest::RegisterVirtualDevice { device: device.clone() });
+        }
+    } else if run.next_step >= run.scenario.steps.len() {
+        let name = run.scenario.name.clone();
+        bt.active_scenario = None;
+        bt_log!(bt, "🔵 Virtual scenario '{}' complete", name);
+        responses.write(BluetoothLEResponse::ScenarioCompleted { name });
+        return;
+    } else {
+        run.elapsed_ms += time.delta_secs_f64() * 1000.0;
+    }
+    
+    while let Some(step) = run.scenario.steps.get(run.next_step) {
+        if f64::from(step.at_ms) > run.elapsed_ms {
+            break;
+        }
+        requests.write(step.request.clone());
+        run.next_step += 1;
+    }
+}
+
 /// Helper function to create common virtual devices for testing
 pub fn create_test_virtual_devices() -> Vec<VirtualDevice> {
+    virtual_devices_seen_at(Some(wall_clock_ms()))
+}
+
+/// The stock virtual collar, feeder and tracker, stamped with `last_seen`
+fn virtual_devices_seen_at(last_seen: Option<f64>) -> Vec<VirtualDevice> {
     vec![
         // Virtual smart collar
         VirtualDevice {
@@ -554,7 +986,7 @@ pub fn create_test_virtual_devices() -> Vec<VirtualDevice> {
                 services: vec!["uuid_collar_service".to_string()],
                 manufacturer_data: Some("ZephyrCollar_v2.1".to_string()),
                 is_connected: false,
-                last_seen: Some(js_sys::Date::now()),
+                last_seen,
                 battery_level: Some(85),
             },
             command_handlers: [
@@ -583,7 +1015,7 @@ pub fn create_test_virtual_devices() -> Vec<VirtualDevice> {
                 services: vec!["uuid_feeder_service".to_string()],
                 manufacturer_data: Some("ZephyrFeeder_v1.5".to_string()),
                 is_connected: false,
-                last_seen: Some(js_sys::Date::now()),
+                last_seen,
                 battery_level: Some(92),
             },
             command_handlers: [
@@ -601,6 +1033,32 @@ pub fn create_test_virtual_devices() -> Vec<VirtualDevice> {
             state: HashMap::new(),
             auto_responses: true,
         },
+        
+        // Virtual activity tracker
+        VirtualDevice {
+            info: DeviceInfo {
+                id: DeviceId("virtual_tracker_001".to_string()),
+                name: "Test Activity Tracker".to_string(),
+                device_type: BluetoothLEDeviceType::ActivityTracker {
+                    sensors: vec![SensorType::HeartRate, SensorType::Temperature, SensorType::Accelerometer],
+                },
+                rssi: -52,
+                services: vec!["uuid_tracker_service".to_string()],
+                manufacturer_data: Some("ZephyrTracker_v1.0".to_string()),
+                is_connected: false,
+                last_seen,
+                battery_level: Some(78),
+            },
+            command_handlers: [
+                ("GetSensorData".to_string(), VirtualCommandHandler {
+                    command_pattern: "GetSensorData".to_string(),
+                    response_template: "Sensor data".to_string(),
+                    delay_ms: 40,
+                }),
+            ].into(),
+            state: HashMap::new(),
+            auto_responses: true,
+        },
     ]
 }
 
@@ -622,4 +1080,347 @@ pub fn connect_to_device(
     device_id: DeviceId,
 ) {
     bt_requests.write(BluetoothLERequest::Connect { device_id });
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_tracker_returns_heart_rate() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let command = ZephyrCommand::TrackerCommands {
+            command: TrackerCommand::GetSensorData { sensor: SensorType::HeartRate },
+        };
+        match virtual_zephyr_response(&command, &mut rng, 1234) {
+            ZephyrResponse::SensorData { sensor, values, timestamp } => {
+                assert_eq!(sensor, SensorType::HeartRate);
+                assert_eq!(values.len(), 1);
+                assert!((60.0..100.0).contains(&values[0]), "{:?}", values);
+                assert_eq!(timestamp, 1234);
+            }
+            other => panic!("expected SensorData, got {:?}", other),
+        }
+
+        // Accelerometer readings are 3-axis with gravity on z
+        let accel = virtual_sensor_reading(&SensorType::Accelerometer, &mut rng);
+        assert_eq!(accel.len(), 3);
+        assert!((accel[2] - 1.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_virtual_raw_write_echoes_and_updates_state() {
+        let mut device = VirtualDevice {
+            info: test_device("virtual_uart_001", BluetoothLEDeviceType::TestDevice { device_name: "UART".to_string() }),
+            command_handlers: HashMap::new(),
+            state: HashMap::new(),
+            auto_responses: true,
+        };
+        let characteristic = "6e400002-b5a3-f393-e0a9-e50e24dcca9e";
+        let response = device.write_raw("6e400001-b5a3-f393-e0a9-e50e24dcca9e", characteristic, &[0x01, 0xab, 0xff]);
+        match response {
+            ZephyrResponse::RawData { characteristic_uuid, data_hex, .. } => {
+                assert_eq!(characteristic_uuid, characteristic);
+                assert_eq!(data_hex, "01abff");
+            }
+            other => panic!("expected RawData, got {:?}", other),
+        }
+        assert_eq!(device.state.get(characteristic), Some(&serde_json::Value::String("01abff".to_string())));
+
+        // A later write to the same characteristic replaces the stored bytes
+        device.write_raw("6e400001-b5a3-f393-e0a9-e50e24dcca9e", characteristic, &[]);
+        assert_eq!(device.state.get(characteristic), Some(&serde_json::Value::String(String::new())));
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let command = ZephyrCommand::RawCommand {
+            service_uuid: "svc".to_string(),
+            characteristic_uuid: "chr".to_string(),
+            data: vec![0x10, 0x02],
+        };
+        assert!(matches!(virtual_zephyr_response(&command, &mut rng, 0),
+            ZephyrResponse::RawData { data_hex, .. } if data_hex == "1002"));
+    }
+
+    #[test]
+    fn test_routine_bluetooth_logs_hidden_unless_verbose() {
+        let failure = BluetoothLEResponse::CommandFailed {
+            device_id: DeviceId("quiet-collar".to_string()),
+            command: ZephyrCommand::GetBatteryLevel,
+            error: "timeout".to_string(),
+        };
+        assert!(failure.is_error());
+        assert!(!BluetoothLEResponse::ScanStarted.is_error());
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<BluetoothLEManager>()
+            .add_event::<BluetoothLERequest>()
+            .add_event::<BluetoothLEResponse>()
+            .add_systems(Update, (handle_bluetoothle_requests, process_bluetoothle_responses).chain());
+        // Other tests log into the same capture; this device id picks out this test's lines
+        let connect = |app: &mut App| {
+            app.world_mut().send_event(BluetoothLERequest::Connect { device_id: DeviceId("quiet-collar".to_string()) });
+            app.update();
+        };
+
+        // Verbose is off by default: routine traffic is dropped, errors still print
+        connect(&mut app);
+        app.world_mut().send_event(failure.clone());
+        app.update();
+        let lines = bluetooth_log_lines("quiet-collar");
+        assert_eq!(lines.len(), 1, "{:?}", lines);
+        assert!(lines[0].contains("CommandFailed"));
+
+        app.world_mut().send_event(BluetoothLERequest::SetVerboseLogging { enabled: true });
+        app.update();
+        assert!(app.world().resource::<BluetoothLEManager>().verbose_logging);
+        connect(&mut app);
+        let lines = bluetooth_log_lines("quiet-collar");
+        assert!(lines.len() > 1 && lines[1..].iter().all(|line| !line.contains("CommandFailed")), "{:?}", lines);
+        assert!(lines.iter().any(|line| line.contains("Real device connection requested")));
+    }
+
+    #[test]
+    fn test_pushed_response_reaches_event_stream() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<BluetoothLEResponse>()
+            .add_systems(Update, poll_bluetooth_responses);
+
+        let json = r#"{"DeviceDiscovered":{"device":{
+            "id":"collar-01","name":"Test Collar",
+            "device_type":{"TestDevice":{"device_name":"collar"}},
+            "rssi":-42,"services":[],"manufacturer_data":null,
+            "is_connected":false,"last_seen":null,"battery_level":87}}}"#;
+        assert!(send_bluetooth_response_to_bevy(json).is_ok());
+        app.update();
+
+        let events = app.world().resource::<Events<BluetoothLEResponse>>();
+        let mut cursor = events.get_cursor();
+        let received: Vec<_> = cursor.read(events).collect();
+        assert_eq!(received.len(), 1);
+        match received[0] {
+            BluetoothLEResponse::DeviceDiscovered { device } => {
+                assert_eq!(device.id, DeviceId("collar-01".to_string()));
+                assert_eq!(device.battery_level, Some(87));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    fn test_device(id: &str, device_type: BluetoothLEDeviceType) -> DeviceInfo {
+        DeviceInfo {
+            id: DeviceId(id.to_string()),
+            name: id.to_string(),
+            device_type,
+            rssi: -40,
+            services: Vec::new(),
+            manufacturer_data: None,
+            is_connected: false,
+            last_seen: None,
+            battery_level: None,
+        }
+    }
+
+    #[test]
+    fn test_devices_filtered_by_type() {
+        let mut bt = BluetoothLEManager::default();
+        let collar = test_device("virtual_collar_001", BluetoothLEDeviceType::SmartCollar { collar_type: CollarType::TrainingCollar });
+        let feeder = test_device("virtual_feeder_001", BluetoothLEDeviceType::FeedingStation { capacity_ml: 2000 });
+        let emulated = test_device("virtual_collar_002", BluetoothLEDeviceType::VirtualDevice {
+            emulated_type: Box::new(BluetoothLEDeviceType::SmartCollar { collar_type: CollarType::GPSCollar }),
+        });
+        for device in [&collar, &feeder, &emulated] {
+            bt.discovered_devices.insert(device.id.clone(), device.clone());
+        }
+        // A connected collar appears once, with its connected info
+        bt.connected_devices.insert(collar.id.clone(), DeviceInfo { is_connected: true, ..collar.clone() });
+
+        let devices = bt.all_devices();
+        let collars = devices_in_category(&devices, DeviceCategory::parse("SmartCollar").unwrap());
+        let ids: Vec<&str> = collars.iter().map(|d| d.id.0.as_str()).collect();
+        assert_eq!(ids, ["virtual_collar_001", "virtual_collar_002"]);
+        assert!(collars[0].is_connected);
+
+        let feeders = devices_in_category(&devices, DeviceCategory::parse("feeding_station").unwrap());
+        assert_eq!(feeders.len(), 1);
+        assert_eq!(feeders[0].id, feeder.id);
+
+        assert!(devices_in_category(&devices, DeviceCategory::ToyDispenser).is_empty());
+        assert!(DeviceCategory::parse("toaster").is_err());
+    }
+
+    #[test]
+    fn test_reconnect_uses_cached_device_info() {
+        let mut bt = BluetoothLEManager::default();
+        let collar = DeviceInfo {
+            services: vec!["uuid_collar_service".to_string()],
+            ..test_device("collar-01", BluetoothLEDeviceType::SmartCollar { collar_type: CollarType::GPSCollar })
+        };
+        bt.note_discovered(collar.clone(), 1_000.0);
+
+        // A new scan session no longer lists it, but a reconnect restores its known info
+        bt.discovered_devices.clear();
+        assert!(bt.reconnect_from_cache(&collar.id, 5_000.0));
+        let connected = bt.connected_devices.get(&collar.id).expect("populated from cache");
+        assert_eq!(connected.services, collar.services);
+        assert_eq!(connected.device_type, collar.device_type);
+        assert!(connected.is_connected);
+
+        // Entries past the TTL are invalidated
+        bt.connected_devices.clear();
+        let later = 1_000.0 + DEFAULT_DEVICE_CACHE_TTL_MS + 1.0;
+        assert!(!bt.reconnect_from_cache(&collar.id, later));
+        assert!(bt.connected_devices.is_empty());
+        assert!(bt.known_devices.is_empty());
+    }
+
+    #[test]
+    fn test_virtual_sensor_noise_has_its_own_seeded_rng() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::resources::GameRng::from_seed(9))
+            .init_resource::<BluetoothLEManager>()
+            .add_event::<BluetoothLERequest>()
+            .add_event::<BluetoothLEResponse>()
+            .add_systems(Update, handle_bluetoothle_requests);
+        for device in virtual_devices_seen_at(None) {
+            app.world_mut().resource_mut::<BluetoothLEManager>().register_virtual_device(device);
+        }
+        let mut cursor = app.world().resource::<Events<BluetoothLEResponse>>().get_cursor();
+        let mut read_after_enable = |app: &mut App| -> Vec<Vec<f32>> {
+            app.world_mut().send_event(BluetoothLERequest::EnableVirtualNetwork);
+            for _ in 0..3 {
+                app.world_mut().send_event(BluetoothLERequest::SendCommand {
+                    device_id: DeviceId("virtual_tracker_001".to_string()),
+                    command: ZephyrCommand::TrackerCommands {
+                        command: TrackerCommand::GetSensorData { sensor: SensorType::HeartRate },
+                    },
+                    timeout_ms: None,
+                });
+            }
+            app.update();
+            let events = app.world().resource::<Events<BluetoothLEResponse>>();
+            cursor.read(events).filter_map(|response| match response {
+                BluetoothLEResponse::CommandResponse { response: ZephyrResponse::SensorData { values, .. }, .. } => Some(values.clone()),
+                _ => None,
+            }).collect()
+        };
+
+        // Each enable restarts the noise, so a simulated session reads the same values every time
+        let first = read_after_enable(&mut app);
+        assert_eq!(first.len(), 3);
+        assert_ne!(first[0], first[1]);
+        assert_eq!(read_after_enable(&mut app), first);
+
+        // ...without drawing from the gameplay RNG
+        let mut untouched = crate::resources::GameRng::from_seed(9);
+        let mut game_rng = app.world_mut().resource_mut::<crate::resources::GameRng>();
+        assert_eq!(game_rng.rng.gen::<u64>(), untouched.rng.gen::<u64>());
+    }
+
+    #[test]
+    fn test_collar_vibration_follows_feedback_intensity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::resources::FeedbackIntensity::Low)
+            .init_resource::<BluetoothLEManager>()
+            .add_event::<BluetoothLERequest>()
+            .add_event::<BluetoothLEResponse>()
+            .add_systems(Update, handle_bluetoothle_requests);
+        for device in virtual_devices_seen_at(None) {
+            app.world_mut().resource_mut::<BluetoothLEManager>().register_virtual_device(device);
+        }
+        let mut cursor = app.world().resource::<Events<BluetoothLEResponse>>().get_cursor();
+        let mut vibrate = |app: &mut App, intensity: u8| -> Option<u8> {
+            app.world_mut().send_event(BluetoothLERequest::SendCommand {
+                device_id: DeviceId("virtual_collar_001".to_string()),
+                command: ZephyrCommand::CollarCommands { command: CollarCommand::Vibrate { intensity, duration_ms: 300 } },
+                timeout_ms: None,
+            });
+            app.update();
+            let events = app.world().resource::<Events<BluetoothLEResponse>>();
+            cursor.read(events).find_map(|response| match response {
+                BluetoothLEResponse::CommandResponse {
+                    command: ZephyrCommand::CollarCommands { command: CollarCommand::Vibrate { intensity, .. } }, ..
+                } => Some(*intensity),
+                _ => None,
+            })
+        };
+
+        app.world_mut().send_event(BluetoothLERequest::EnableVirtualNetwork);
+        // Low feedback buzzes at a quarter strength
+        assert_eq!(vibrate(&mut app, 40), Some(10));
+        assert!(app.world().resource::<BluetoothLEManager>().virtual_command_log.last().unwrap().command.contains("intensity: 10"));
+        app.insert_resource(crate::resources::FeedbackIntensity::High);
+        assert_eq!(vibrate(&mut app, 40), Some(40));
+
+        // Other commands are untouched
+        let battery = ZephyrCommand::GetBatteryLevel.with_vibration_scale(0.0);
+        assert!(matches!(battery, ZephyrCommand::GetBatteryLevel));
+    }
+
+    #[test]
+    fn test_collar_pairing_scenario_produces_scripted_responses() {
+        use bevy::time::TimeUpdateStrategy;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .init_resource::<BluetoothLEManager>()
+            .add_event::<BluetoothLERequest>()
+            .add_event::<BluetoothLEResponse>()
+            .add_systems(Update, (virtual_scenario_system, handle_bluetoothle_requests).chain());
+        let mut cursor = app.world().resource::<Events<BluetoothLEResponse>>().get_cursor();
+
+        app.world_mut().send_event(BluetoothLERequest::RunVirtualScenario { name: "collar-pairing".to_string() });
+        let mut seen = Vec::new();
+        for _ in 0..40 {
+            app.update();
+            let events = app.world().resource::<Events<BluetoothLEResponse>>();
+            seen.extend(cursor.read(events).cloned());
+        }
+
+        let collar = DeviceId("virtual_collar_001".to_string());
+        let summary: Vec<String> = seen.iter().map(|response| match response {
+            BluetoothLEResponse::ScenarioStarted { name } => format!("started {}", name),
+            BluetoothLEResponse::VirtualNetworkEnabled => "network".to_string(),
+            BluetoothLEResponse::VirtualDeviceRegistered { device_id } => format!("registered {}", device_id.0),
+            BluetoothLEResponse::ScanStarted => "scan".to_string(),
+            BluetoothLEResponse::DeviceDiscovered { device } => format!("discovered {}", device.id.0),
+            BluetoothLEResponse::ScanStopped => "scan stopped".to_string(),
+            BluetoothLEResponse::Connected { device_id } => format!("connected {}", device_id.0),
+            BluetoothLEResponse::CommandResponse { device_id, response, .. } => match response {
+                ZephyrResponse::BatteryLevel { percentage, .. } => format!("{} battery {}", device_id.0, percentage),
+                other => format!("{} {:?}", device_id.0, other),
+            },
+            BluetoothLEResponse::ScenarioCompleted { name } => format!("completed {}", name),
+            other => format!("{:?}", other),
+        }).collect();
+        assert_eq!(summary, vec![
+            "started collar-pairing",
+            "network",
+            "registered virtual_collar_001",
+            "scan",
+            "discovered virtual_collar_001",
+            "scan stopped",
+            "connected virtual_collar_001",
+            "virtual_collar_001 battery 85",
+            "virtual_collar_001 Success",
+            "completed collar-pairing",
+        ]);
+
+        // Commands reached the virtual device and the scenario is no longer active
+        let bt = app.world().resource::<BluetoothLEManager>();
+        assert!(bt.active_scenario.is_none());
+        assert!(bt.connected_devices.contains_key(&collar));
+        assert_eq!(bt.virtual_command_log.len(), 2);
+
+        // Unknown names are reported instead of silently ignored
+        assert!(builtin_scenario("moon-landing").is_none());
+        app.world_mut().send_event(BluetoothLERequest::RunVirtualScenario { name: "moon-landing".to_string() });
+        app.update();
+        let events = app.world().resource::<Events<BluetoothLEResponse>>();
+        assert!(matches!(cursor.read(events).next(), Some(BluetoothLEResponse::Error { error: BluetoothLEError::PlatformError { .. } })));
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use bevy::asset::RenderAssetUsages;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
-// No explicit sampler override; default sampler is fine
+#[cfg(feature = "camera_sprite_preview")]
+use crate::resources::PixelPerfect;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -12,25 +13,50 @@ macro_rules! console_warn { ($($arg:tt)*) => { web_sys::console::warn_1(&format!
 #[derive(Resource, Default, Clone)]
 pub struct CameraFrame(pub Option<Vec<u8>>); // RGB bytes (web path), or decoded sample
 
+/// Source id used when JS doesn't name a camera (single-camera setups)
+pub const DEFAULT_CAMERA_SOURCE: &str = "default";
+
+/// Latest frame per camera source (front, rear, ...); `CameraFrame` mirrors the default source
+#[derive(Resource, Default, Clone)]
+pub struct CameraSources {
+    pub frames: std::collections::HashMap<String, CameraFrame>,
+}
+
 #[derive(Resource, Default, Debug, Clone)]
 pub struct CameraStats {
     pub total_frames: u64,
     pub throttled_frames: u64,
+    pub dropped_frames: u64, // backlog overflow, oldest frames discarded
+    pub non_monotonic_frames: u64, // timestamp went backwards (clock jump or out-of-order delivery)
     pub last_ts: f64,
+    pub fps: f32, // smoothed arrival rate of default-source frames
+    pub last_frame_size: Option<UVec2>,
+}
+
+/// Per-update cap on camera frame processing so a burst of frames can't spike frame time
+#[derive(Resource, Debug, Clone)]
+pub struct CameraDrainBudget {
+    pub max_frames_per_update: usize,
+    pub max_queued: usize, // frames left waiting beyond this are dropped (oldest first)
+}
+
+impl Default for CameraDrainBudget {
+    fn default() -> Self { Self { max_frames_per_update: 4, max_queued: 8 } }
 }
 
 #[derive(Resource, Debug, Clone)]
 pub struct FrameThrottle {
     pub min_interval_ms: f64,
-    pub last_emit_ts: f64,
+    pub last_emit_ts: std::collections::HashMap<String, f64>, // per source
 }
 
 impl Default for FrameThrottle {
-    fn default() -> Self { Self { min_interval_ms: 100.0, last_emit_ts: 0.0 } }
+    fn default() -> Self { Self { min_interval_ms: 100.0, last_emit_ts: std::collections::HashMap::new() } }
 }
 
 #[derive(Event, Clone, Debug, Serialize, Deserialize)]
 pub struct NewFrameEvent {
+    pub source_id: String,
     pub rgba: Vec<u8>,
     pub width: u32,
     pub height: u32,
@@ -41,61 +67,143 @@ pub struct NewFrameEvent {
 
 // Thread-local queue to receive frames from JS quickly without blocking Bevy
 thread_local! {
-    static CAMERA_QUEUE: std::cell::RefCell<Vec<(u32, u32, Vec<u8>, f64)>> = std::cell::RefCell::new(Vec::new());
+    static CAMERA_QUEUE: std::cell::RefCell<Vec<(String, u32, u32, Vec<u8>, f64, u32)>> = std::cell::RefCell::new(Vec::new());
 }
 
+/// Submit an RGB frame; `source_id` distinguishes cameras (omit for the default source) and `orientation`
+/// is the clockwise rotation (0/90/180/270) that makes the frame upright, e.g. 90 for a portrait phone
 #[wasm_bindgen]
-pub fn submit_camera_frame(width: u32, height: u32, data: js_sys::Uint8Array, ts: f64) -> Result<(), JsValue> {
+pub fn submit_camera_frame(width: u32, height: u32, data: js_sys::Uint8Array, ts: f64, source_id: Option<String>, orientation: Option<u32>) -> Result<(), JsValue> {
+    let orientation = orientation.unwrap_or(0);
+    if !matches!(orientation, 0 | 90 | 180 | 270) {
+        return Err(JsValue::from_str(&format!("Invalid camera orientation: {} (expected 0, 90, 180 or 270)", orientation)));
+    }
     let mut buf = vec![0u8; data.length() as usize];
     data.copy_to(&mut buf[..]);
-    CAMERA_QUEUE.with(|q| q.borrow_mut().push((width, height, buf, ts)));
+    queue_camera_frame(source_id.unwrap_or_else(|| DEFAULT_CAMERA_SOURCE.to_string()), width, height, buf, ts, orientation);
     Ok(())
 }
 
+fn queue_camera_frame(source_id: String, width: u32, height: u32, buf: Vec<u8>, ts: f64, orientation: u32) {
+    CAMERA_QUEUE.with(|q| q.borrow_mut().push((source_id, width, height, buf, ts, orientation)));
+}
+
+/// Rotate an RGBA frame clockwise by `orientation` degrees (0/90/180/270); returns the pixels and upright size
+pub fn rotate_rgba(rgba: Vec<u8>, width: u32, height: u32, orientation: u32) -> (Vec<u8>, u32, u32) {
+    let (w, h) = (width as usize, height as usize);
+    let (out_w, out_h) = match orientation {
+        90 | 270 => (h, w),
+        180 => (w, h),
+        _ => return (rgba, width, height),
+    };
+    let mut out = vec![0u8; rgba.len()];
+    for y in 0..h {
+        for x in 0..w {
+            let (dx, dy) = match orientation {
+                90 => (h - 1 - y, x),
+                180 => (w - 1 - x, h - 1 - y),
+                _ => (y, w - 1 - x),
+            };
+            let src = (y * w + x) * 4;
+            let dst = (dy * out_w + dx) * 4;
+            out[dst..dst + 4].copy_from_slice(&rgba[src..src + 4]);
+        }
+    }
+    (out, out_w as u32, out_h as u32)
+}
+
+/// Shortest believable gap between frames; anything tighter would read as an FPS spike
+const MIN_FRAME_INTERVAL_MS: f64 = 1000.0 / 240.0;
+
+/// Milliseconds between two frame timestamps, or None when there's no previous frame or time didn't move forward
+pub fn frame_interval_ms(prev_ts: f64, ts: f64) -> Option<f64> {
+    let dt = ts - prev_ts;
+    (prev_ts > 0.0 && dt.is_finite() && dt > 0.0).then(|| dt.max(MIN_FRAME_INTERVAL_MS))
+}
+
+/// Fold one frame interval into the smoothed FPS; missing intervals leave it untouched
+pub fn smoothed_fps(current: f32, interval_ms: Option<f64>) -> f32 {
+    let Some(dt_ms) = interval_ms else { return current };
+    let inst_fps = (1000.0 / dt_ms) as f32;
+    if current == 0.0 { inst_fps } else { current * 0.9 + inst_fps * 0.1 }
+}
+
 fn drain_camera_queue(
     mut frame_res: ResMut<CameraFrame>,
+    mut sources: ResMut<CameraSources>,
     mut stats: ResMut<CameraStats>,
     mut throttle: ResMut<FrameThrottle>,
     mut ev: EventWriter<NewFrameEvent>,
     ctrl: Option<Res<CameraPreviewControl>>,
+    budget: Option<Res<CameraDrainBudget>>,
+    mut last_default_ts: Local<f64>,
 ) {
-    CAMERA_QUEUE.with(|q| {
+    let budget = budget.map(|b| b.clone()).unwrap_or_default();
+    let batch: Vec<_> = CAMERA_QUEUE.with(|q| {
         let mut q = q.borrow_mut();
-        for (w, h, data, ts) in q.drain(..) {
-            stats.total_frames += 1;
-            // Throttle emission to systems if needed
-            let emit = if throttle.last_emit_ts <= 0.0 { true } else { (ts - throttle.last_emit_ts) >= throttle.min_interval_ms };
-            frame_res.0 = Some(data);
-            stats.last_ts = ts;
-            if emit {
-                throttle.last_emit_ts = ts;
-                // Convert RGB -> RGBA for sprite texture
-                let rgb = frame_res.0.as_ref().unwrap();
-                let mut rgba = vec![0u8; (w as usize * h as usize) * 4];
-                let mut j = 0usize;
-                for i in (0..rgba.len()).step_by(4) {
-                    rgba[i] = rgb[j];
-                    rgba[i + 1] = rgb[j + 1];
-                    rgba[i + 2] = rgb[j + 2];
-                    rgba[i + 3] = 255;
-                    j += 3;
-                }
-                let mirror_x = ctrl.as_ref().map(|c| c.mirror_x).unwrap_or(false);
-                let scale = ctrl.as_ref().map(|c| c.scale).unwrap_or(0.5);
-                ev.write(NewFrameEvent { rgba, width: w, height: h, mirror_x, scale, ts });
-            } else {
-                stats.throttled_frames += 1;
-            }
+        let take = q.len().min(budget.max_frames_per_update);
+        let batch = q.drain(..take).collect();
+        // Keep the backlog bounded; stale frames are worthless for a live preview
+        let overflow = q.len().saturating_sub(budget.max_queued);
+        if overflow > 0 {
+            q.drain(..overflow);
+            stats.dropped_frames += overflow as u64;
         }
+        batch
     });
+    for (source_id, w, h, data, ts, orientation) in batch {
+        stats.total_frames += 1;
+        // Throttle emission to systems if needed (per source)
+        let last_emit_ts = throttle.last_emit_ts.get(&source_id).copied().unwrap_or(0.0);
+        let backwards = last_emit_ts > 0.0 && ts < last_emit_ts;
+        if backwards {
+            // Re-baseline instead of throttling until the clock catches up again
+            stats.non_monotonic_frames += 1;
+            if stats.non_monotonic_frames % 60 == 1 {
+                console_warn!("⏪ Camera timestamp went backwards on {}: {} < {} ({} so far)",
+                    source_id, ts, last_emit_ts, stats.non_monotonic_frames);
+            }
+        }
+        let emit = last_emit_ts <= 0.0 || backwards || (ts - last_emit_ts) >= throttle.min_interval_ms;
+        if source_id == DEFAULT_CAMERA_SOURCE {
+            frame_res.0 = Some(data.clone());
+            stats.fps = smoothed_fps(stats.fps, frame_interval_ms(*last_default_ts, ts));
+            *last_default_ts = ts;
+        }
+        let frame = sources.frames.entry(source_id.clone()).or_default();
+        frame.0 = Some(data);
+        stats.last_ts = ts;
+        stats.last_frame_size = Some(if orientation % 180 == 90 { UVec2::new(h, w) } else { UVec2::new(w, h) });
+        if emit {
+            throttle.last_emit_ts.insert(source_id.clone(), ts);
+            // Convert RGB -> RGBA for sprite texture
+            let rgb = frame.0.as_ref().unwrap();
+            let mut rgba = vec![0u8; (w as usize * h as usize) * 4];
+            let mut j = 0usize;
+            for i in (0..rgba.len()).step_by(4) {
+                rgba[i] = rgb[j];
+                rgba[i + 1] = rgb[j + 1];
+                rgba[i + 2] = rgb[j + 2];
+                rgba[i + 3] = 255;
+                j += 3;
+            }
+            // Upright before the preview and posture processing see it
+            let (rgba, width, height) = rotate_rgba(rgba, w, h, orientation);
+            let mirror_x = ctrl.as_ref().map(|c| c.mirror_x).unwrap_or(false);
+            let scale = ctrl.as_ref().map(|c| c.scale).unwrap_or(0.5);
+            ev.write(NewFrameEvent { source_id, rgba, width, height, mirror_x, scale, ts });
+        } else {
+            stats.throttled_frames += 1;
+        }
+    }
 }
 
 fn log_camera_stats(stats: Res<CameraStats>) {
     // Lightweight periodic log every 60 frames
     if stats.total_frames > 0 && stats.total_frames % 60 == 0 {
         console_log!(
-            "📈 Camera frames: total={}, throttled={}, last_ts={}",
-            stats.total_frames, stats.throttled_frames, stats.last_ts
+            "📈 Camera frames: total={}, throttled={}, dropped={}, fps={:.1}, last_ts={}",
+            stats.total_frames, stats.throttled_frames, stats.dropped_frames, stats.fps, stats.last_ts
         );
     }
 }
@@ -105,9 +213,12 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<CameraFrame>()
+            .init_resource::<CameraSources>()
             .init_resource::<CameraStats>()
             .init_resource::<FrameThrottle>()
+            .init_resource::<CameraDrainBudget>()
             .init_resource::<CameraPreviewControl>()
+            .init_resource::<PostureRecognitionState>()
             .add_event::<NewFrameEvent>();
 
         #[cfg(feature = "camera_sprite_preview")]
@@ -125,16 +236,202 @@ impl Plugin for CameraPlugin {
 }
 
 // Stub posture recognition preprocessor (to be replaced with real model)
-#[derive(Resource, Default, Debug, Clone)]
+#[derive(Resource, Debug, Clone)]
 pub struct PostureRecognitionState {
-    pub last_label: String,
+    pub last_label: String, // smoothed label fed to gameplay
     pub frames_processed: u64,
+    pub smoothing_window: usize, // majority vote over this many recent frames
+    pub confidence_threshold: f32, // raw labels below this count as "unknown"
+    recent_labels: std::collections::VecDeque<String>,
+}
+
+pub const UNKNOWN_POSTURE: &str = "unknown";
+
+impl Default for PostureRecognitionState {
+    fn default() -> Self {
+        Self {
+            last_label: UNKNOWN_POSTURE.to_string(),
+            frames_processed: 0,
+            smoothing_window: 5,
+            confidence_threshold: 0.6,
+            recent_labels: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+impl PostureRecognitionState {
+    /// Feed one raw classifier output; returns the smoothed label (ties keep the previous label)
+    pub fn push_label(&mut self, label: &str, confidence: f32) -> &str {
+        let label = if confidence >= self.confidence_threshold { label } else { UNKNOWN_POSTURE };
+        self.recent_labels.push_back(label.to_string());
+        let window = self.smoothing_window.max(1);
+        while self.recent_labels.len() > window {
+            self.recent_labels.pop_front();
+        }
+
+        let mut votes: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+        for recent in &self.recent_labels {
+            *votes.entry(recent.as_str()).or_default() += 1;
+        }
+        let best = votes.values().copied().max().unwrap_or(0);
+        let leaders: Vec<&str> = votes.iter().filter(|(_, n)| **n == best).map(|(l, _)| *l).collect();
+        if leaders.len() == 1 && leaders[0] != self.last_label {
+            self.last_label = leaders[0].to_string();
+        }
+        &self.last_label
+    }
 }
 
 // Simple on-screen texture preview for validation
 #[derive(Debug, Clone)]
 pub enum PreviewAnchor { TopLeft, TopRight, BottomLeft, BottomRight }
 
+impl PreviewAnchor {
+    /// Parse the JS anchor name; unknown names fall back to the top-right corner
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "TopLeft" => PreviewAnchor::TopLeft,
+            "BottomLeft" => PreviewAnchor::BottomLeft,
+            "BottomRight" => PreviewAnchor::BottomRight,
+            _ => PreviewAnchor::TopRight,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PreviewAnchor::TopLeft => "TopLeft",
+            PreviewAnchor::TopRight => "TopRight",
+            PreviewAnchor::BottomLeft => "BottomLeft",
+            PreviewAnchor::BottomRight => "BottomRight",
+        }
+    }
+}
+
+/// CSS `env(safe-area-inset-*)` values in logical pixels (notches, rounded corners, home bar)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// Preview center in world space for the given anchor, pushed inward past any safe-area insets
+pub fn preview_position(anchor: &PreviewAnchor, w: f32, h: f32, margin: f32, offset: Vec2, insets: SafeAreaInsets) -> Vec2 {
+    let left = -w * 0.5 + margin + insets.left;
+    let right = w * 0.5 - margin - insets.right;
+    let top = h * 0.5 - margin - insets.top;
+    let bottom = -h * 0.5 + margin + insets.bottom;
+    let base = match anchor {
+        PreviewAnchor::TopLeft => Vec2::new(left, top),
+        PreviewAnchor::TopRight => Vec2::new(right, top),
+        PreviewAnchor::BottomLeft => Vec2::new(left, bottom),
+        PreviewAnchor::BottomRight => Vec2::new(right, bottom),
+    };
+    base + offset
+}
+
+/// Sanitize requested preview/overlay layers: the preview stays above critters, the overlay above the preview
+pub fn preview_layers(preview_z: f32, overlay_z: f32) -> (f32, f32) {
+    let preview_z = if preview_z.is_finite() { preview_z.max(crate::components::CRITTER_Z + 1.0) } else { 500.0 };
+    let overlay_z = if overlay_z.is_finite() { overlay_z.max(preview_z + 1.0) } else { preview_z + 1.0 };
+    (preview_z, overlay_z)
+}
+
+/// Whether a frame stamped `ts` should refresh the preview texture, given the last refresh at `last_ts`
+pub fn preview_frame_due(last_ts: Option<f64>, ts: f64, preview_fps: f32) -> bool {
+    match last_ts {
+        _ if preview_fps <= 0.0 => true,
+        None => true,
+        // A clock that jumped backwards re-baselines rather than freezing the thumbnail
+        Some(last) if ts < last => true,
+        Some(last) => ts - last >= 1000.0 / preview_fps as f64,
+    }
+}
+
+/// Preview frame size assumed before the first camera frame arrives (JS capture default)
+pub const NOMINAL_PREVIEW_FRAME: Vec2 = Vec2::new(640.0, 480.0);
+
+/// World-space area covered by the preview sprite, or None while the preview is disabled
+pub fn preview_exclusion_rect(ctrl: &CameraPreviewControl, window: Vec2, frame: Vec2) -> Option<Rect> {
+    if !ctrl.enabled {
+        return None;
+    }
+    let offset = Vec2::new(ctrl.offset_x, ctrl.offset_y);
+    let center = preview_position(&ctrl.anchor, window.x, window.y, ctrl.margin, offset, ctrl.safe_area);
+    Some(Rect::from_center_size(center, frame * ctrl.scale.max(0.01)))
+}
+
+/// CPU-side filter applied to the preview buffer to visualize what the recognizer "sees"
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PreviewFilter {
+    #[default]
+    None,
+    Grayscale,
+    Edges,
+}
+
+impl PreviewFilter {
+    pub fn from_str(mode: &str) -> Option<Self> {
+        match mode {
+            "None" | "none" => Some(PreviewFilter::None),
+            "Grayscale" | "grayscale" => Some(PreviewFilter::Grayscale),
+            "Edges" | "edges" | "sobel" => Some(PreviewFilter::Edges),
+            _ => None,
+        }
+    }
+}
+
+/// Apply the preview filter to an RGBA buffer in place
+pub fn apply_preview_filter(filter: PreviewFilter, rgba: &mut Vec<u8>, width: u32, height: u32) {
+    match filter {
+        PreviewFilter::None => {}
+        PreviewFilter::Grayscale => grayscale_rgba(rgba),
+        PreviewFilter::Edges => *rgba = sobel_edges_rgba(rgba, width, height),
+    }
+}
+
+fn luma(r: u8, g: u8, b: u8) -> u8 {
+    // Rec. 601 luma weights
+    (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32).round().clamp(0.0, 255.0) as u8
+}
+
+/// Convert RGBA to grayscale (R=G=B), alpha untouched
+pub fn grayscale_rgba(rgba: &mut [u8]) {
+    for px in rgba.chunks_exact_mut(4) {
+        let y = luma(px[0], px[1], px[2]);
+        px[0] = y;
+        px[1] = y;
+        px[2] = y;
+    }
+}
+
+/// Sobel edge magnitude of an RGBA buffer, returned as opaque grayscale RGBA
+pub fn sobel_edges_rgba(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let (w, h) = (width as usize, height as usize);
+    let mut out = vec![0u8; w * h * 4];
+    if w == 0 || h == 0 || rgba.len() < w * h * 4 { return out; }
+    let gray: Vec<f32> = rgba.chunks_exact(4).map(|p| luma(p[0], p[1], p[2]) as f32).collect();
+    let at = |x: usize, y: usize| gray[y * w + x];
+    for y in 0..h {
+        for x in 0..w {
+            let i = (y * w + x) * 4;
+            out[i + 3] = 255;
+            // Leave the 1px border black; the kernel needs all 8 neighbours
+            if x == 0 || y == 0 || x + 1 == w || y + 1 == h { continue; }
+            let gx = -at(x - 1, y - 1) - 2.0 * at(x - 1, y) - at(x - 1, y + 1)
+                + at(x + 1, y - 1) + 2.0 * at(x + 1, y) + at(x + 1, y + 1);
+            let gy = -at(x - 1, y - 1) - 2.0 * at(x, y - 1) - at(x + 1, y - 1)
+                + at(x - 1, y + 1) + 2.0 * at(x, y + 1) + at(x + 1, y + 1);
+            let mag = (gx * gx + gy * gy).sqrt().min(255.0) as u8;
+            out[i] = mag;
+            out[i + 1] = mag;
+            out[i + 2] = mag;
+        }
+    }
+    out
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct CameraPreviewControl {
     pub enabled: bool,
@@ -144,11 +441,17 @@ pub struct CameraPreviewControl {
     pub offset_x: f32,
     pub offset_y: f32,
     pub mirror_x: bool,
+    pub filter: PreviewFilter,
+    pub source_id: String, // which camera source the preview shows
+    pub safe_area: SafeAreaInsets,
+    pub preview_z: f32, // above critters, below the FPS overlay and UI
+    pub overlay_z: f32,
+    pub preview_fps: f32, // texture uploads per second, on top of FrameThrottle; 0 = every emitted frame
 }
 
 impl Default for CameraPreviewControl {
     fn default() -> Self {
-        Self { enabled: true, scale: 0.5, anchor: PreviewAnchor::TopRight, margin: 12.0, offset_x: 0.0, offset_y: 0.0, mirror_x: false }
+        Self { enabled: true, scale: 0.5, anchor: PreviewAnchor::TopRight, margin: 12.0, offset_x: 0.0, offset_y: 0.0, mirror_x: false, filter: PreviewFilter::None, source_id: DEFAULT_CAMERA_SOURCE.to_string(), safe_area: SafeAreaInsets::default(), preview_z: 500.0, overlay_z: 1002.0, preview_fps: 10.0 }
     }
 }
 #[derive(Resource, Default)]
@@ -175,6 +478,7 @@ pub struct CameraPreviewEntity(pub Entity);
 pub fn spawn_camera_preview(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
+    pixel_perfect: Option<Res<PixelPerfect>>,
 ) {
     let mut img = Image::new_fill(
         Extent3d { width: 2, height: 2, depth_or_array_layers: 1 },
@@ -183,7 +487,7 @@ pub fn spawn_camera_preview(
         TextureFormat::Rgba8UnormSrgb,
         RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
     );
-    // leave default sampler
+    img.sampler = PixelPerfect::sampler(pixel_perfect.is_some_and(|p| p.camera_preview));
     let tex = images.add(img);
     commands.insert_resource(CameraPreviewHandle(tex.clone()));
 
@@ -212,9 +516,13 @@ pub fn update_camera_preview_system(
     mut q_sprite: Query<&mut Sprite>,
     mut q_visibility: Query<&mut Visibility>,
     ctrl: Option<Res<CameraPreviewControl>>,
+    pixel_perfect: Option<Res<PixelPerfect>>,
+    view: Option<Res<crate::resources::CameraView>>,
     windows: Query<&Window>,
+    mut last_preview_ts: Local<Option<f64>>,
 ) {
     let Some(handle) = handle else { return; };
+    let view = view.map(|v| *v).unwrap_or_default();
     let Some(preview_entity) = preview_entity else { return; };
     
     // Check if preview is enabled
@@ -225,6 +533,12 @@ pub fn update_camera_preview_system(
         *vis = if enabled { Visibility::Visible } else { Visibility::Hidden };
     }
     
+    // Layering follows the control even between frames
+    let preview_z = ctrl.as_ref().map(|c| c.preview_z).unwrap_or(500.0);
+    if let Ok(mut tf) = q_transform.get_mut(preview_entity.0) {
+        tf.translation.z = preview_z;
+    }
+    
     // Only process frame updates if enabled
     if !enabled {
         return;
@@ -234,36 +548,42 @@ pub fn update_camera_preview_system(
     let margin = ctrl.as_ref().map(|c| c.margin).unwrap_or(12.0);
     let offx = ctrl.as_ref().map(|c| c.offset_x).unwrap_or(0.0);
     let offy = ctrl.as_ref().map(|c| c.offset_y).unwrap_or(0.0);
-    for e in ev.read() {
+    let filter = ctrl.as_ref().map(|c| c.filter).unwrap_or_default();
+    let insets = ctrl.as_ref().map(|c| c.safe_area).unwrap_or_default();
+    let source_id = ctrl.as_ref().map(|c| c.source_id.clone()).unwrap_or_else(|| DEFAULT_CAMERA_SOURCE.to_string());
+    let nearest = pixel_perfect.as_ref().is_some_and(|p| p.camera_preview);
+    let preview_fps = ctrl.as_ref().map(|c| c.preview_fps).unwrap_or(10.0);
+    for e in ev.read().filter(|e| e.source_id == source_id) {
+        // Uploading the texture is the costly part; recognition still gets every emitted frame
+        if !preview_frame_due(*last_preview_ts, e.ts, preview_fps) {
+            continue;
+        }
+        *last_preview_ts = Some(e.ts);
         // Update image
         if let Some(img) = images.get_mut(&handle.0) {
+            let mut rgba = e.rgba.clone();
+            apply_preview_filter(filter, &mut rgba, e.width, e.height);
             *img = Image::new_fill(
                 Extent3d { width: e.width, height: e.height, depth_or_array_layers: 1 },
                 TextureDimension::D2,
-                &e.rgba,
+                &rgba,
                 TextureFormat::Rgba8UnormSrgb,
                 RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
             );
-            // leave default sampler
+            img.sampler = PixelPerfect::sampler(nearest);
         }
 
         if let Ok(mut spr) = q_sprite.get_mut(preview_entity.0) {
             spr.flip_x = e.mirror_x;
         }
         if let Ok(mut tf) = q_transform.get_mut(preview_entity.0) {
-            tf.scale = Vec3::splat(e.scale.max(0.01));
+            // Keep the same on-screen size and corner however the camera is panned/zoomed
+            tf.scale = Vec3::splat(e.scale.max(0.01) / view.zoom);
             if let Ok(win) = windows.single() {
-                let w = win.width();
-                let h = win.height();
-                let (x, y) = match anchor {
-                    PreviewAnchor::TopLeft => ( -w * 0.5 + margin + offx,  h * 0.5 - margin + offy),
-                    PreviewAnchor::TopRight => ( w * 0.5 - margin + offx,  h * 0.5 - margin + offy),
-                    PreviewAnchor::BottomLeft => ( -w * 0.5 + margin + offx, -h * 0.5 + margin + offy),
-                    PreviewAnchor::BottomRight => ( w * 0.5 - margin + offx, -h * 0.5 + margin + offy),
-                };
-                tf.translation.x = x;
-                tf.translation.y = y;
-                tf.translation.z = 0.0;
+                let pos = preview_position(&anchor, win.width(), win.height(), margin, Vec2::new(offx, offy), insets);
+                let pos = view.screen_offset_to_world(pos);
+                tf.translation.x = pos.x;
+                tf.translation.y = pos.y;
             }
         }
     }
@@ -277,7 +597,9 @@ fn fps_overlay_system(
     mut q_text: Query<&mut Text>,
     mut q_tf: Query<&mut Transform>,
     windows: Query<&Window>,
+    ctrl: Option<Res<CameraPreviewControl>>,
 ) {
+    let overlay_z = ctrl.as_ref().map(|c| c.overlay_z).unwrap_or(1002.0);
     for e in ev.read() {
         if state.fps_entity.is_none() {
             let ent = commands
@@ -293,30 +615,30 @@ fn fps_overlay_system(
             state.fps_entity = Some(ent);
             state.last_ts = e.ts;
         }
-        let dt_ms = (e.ts - state.last_ts).max(1.0);
-        let inst_fps = (1000.0 / dt_ms) as f32;
-        state.smoothed_fps = if state.smoothed_fps == 0.0 { inst_fps } else { state.smoothed_fps * 0.9 + inst_fps * 0.1 };
+        // Backwards or repeated timestamps skip the FPS update but still re-baseline
+        state.smoothed_fps = smoothed_fps(state.smoothed_fps, frame_interval_ms(state.last_ts, e.ts));
         state.last_ts = e.ts;
         if let Some(fps_ent) = state.fps_entity {
             if let Ok(mut text) = q_text.get_mut(fps_ent) {
                 text.0 = format!("FPS: {:.1}", state.smoothed_fps);
             }
             if let (Ok(win), Ok(mut tf)) = (windows.single(), q_tf.get_mut(fps_ent)) {
-                tf.translation = Vec3::new(win.width() * 0.5 - 80.0, win.height() * 0.5 - 24.0, 1002.0);
+                tf.translation = Vec3::new(win.width() * 0.5 - 80.0, win.height() * 0.5 - 24.0, overlay_z);
             }
         }
     }
 }
 
 fn kinematics_preprocess(
-    mut state: Local<PostureRecognitionState>,
+    mut state: ResMut<PostureRecognitionState>,
     mut ev: EventReader<NewFrameEvent>,
     frame: Res<CameraFrame>,
 ) {
     for _ in ev.read() {
         state.frames_processed += 1;
         // Placeholder: we have frame bytes in frame.0 as RGB; no actual inference here.
-        state.last_label = if state.frames_processed % 120 == 0 { "standing".into() } else { "unknown".into() };
+        let (raw_label, confidence) = if state.frames_processed % 120 == 0 { ("standing", 0.7) } else { (UNKNOWN_POSTURE, 1.0) };
+        state.push_label(raw_label, confidence);
         if state.frames_processed % 60 == 0 {
             console_warn!("🤖 posture(recognizer): frames={} label={}", state.frames_processed, state.last_label);
         }
@@ -324,3 +646,268 @@ fn kinematics_preprocess(
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_area_insets_shift_preview_inward() {
+        let (w, h, margin) = (400.0, 800.0, 12.0);
+        let plain = preview_position(&PreviewAnchor::TopRight, w, h, margin, Vec2::ZERO, SafeAreaInsets::default());
+        let insets = SafeAreaInsets { top: 44.0, right: 20.0, bottom: 34.0, left: 0.0 };
+        let inset = preview_position(&PreviewAnchor::TopRight, w, h, margin, Vec2::ZERO, insets);
+        assert_eq!(inset, plain - Vec2::new(20.0, 44.0));
+
+        // Bottom anchors move up by the bottom inset
+        let bottom_plain = preview_position(&PreviewAnchor::BottomLeft, w, h, margin, Vec2::ZERO, SafeAreaInsets::default());
+        let bottom_inset = preview_position(&PreviewAnchor::BottomLeft, w, h, margin, Vec2::ZERO, insets);
+        assert_eq!(bottom_inset, bottom_plain + Vec2::new(0.0, 34.0));
+    }
+
+    #[test]
+    fn test_grayscale_equalizes_channels() {
+        let mut rgba = vec![255, 0, 0, 255, 10, 200, 30, 128, 0, 0, 255, 255];
+        grayscale_rgba(&mut rgba);
+        for px in rgba.chunks_exact(4) {
+            assert_eq!(px[0], px[1]);
+            assert_eq!(px[1], px[2]);
+        }
+        // Alpha is preserved
+        assert_eq!(rgba[7], 128);
+    }
+
+    #[test]
+    fn test_sobel_highlights_boundary() {
+        // 6x6 image: left half black, right half white
+        let (w, h) = (6u32, 6u32);
+        let mut rgba = Vec::new();
+        for _y in 0..h {
+            for x in 0..w {
+                let v = if x < 3 { 0 } else { 255 };
+                rgba.extend_from_slice(&[v, v, v, 255]);
+            }
+        }
+        let edges = sobel_edges_rgba(&rgba, w, h);
+        let px = |x: usize, y: usize| edges[(y * w as usize + x) * 4];
+        // Strong response at the boundary, none inside the flat regions
+        assert!(px(2, 3) > 200);
+        assert!(px(3, 3) > 200);
+        assert_eq!(px(1, 3), 0);
+        assert_eq!(px(4, 3), 0);
+    }
+
+    #[test]
+    fn test_frames_routed_per_source() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<CameraFrame>()
+            .init_resource::<CameraSources>()
+            .init_resource::<CameraStats>()
+            .init_resource::<FrameThrottle>()
+            .add_event::<NewFrameEvent>()
+            .add_systems(Update, drain_camera_queue);
+
+        queue_camera_frame("front".to_string(), 1, 1, vec![10, 20, 30], 1.0, 0);
+        queue_camera_frame("rear".to_string(), 1, 1, vec![40, 50, 60], 1.0, 0);
+        app.update();
+
+        let sources = app.world().resource::<CameraSources>();
+        assert_eq!(sources.frames["front"].0, Some(vec![10, 20, 30]));
+        assert_eq!(sources.frames["rear"].0, Some(vec![40, 50, 60]));
+        // Non-default sources leave the legacy single-frame resource alone
+        assert!(app.world().resource::<CameraFrame>().0.is_none());
+
+        queue_camera_frame("rear".to_string(), 1, 1, vec![1, 2, 3], 200.0, 0);
+        app.update();
+        let sources = app.world().resource::<CameraSources>();
+        assert_eq!(sources.frames["front"].0, Some(vec![10, 20, 30]));
+        assert_eq!(sources.frames["rear"].0, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_drain_respects_per_update_budget() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<CameraFrame>()
+            .init_resource::<CameraSources>()
+            .init_resource::<CameraStats>()
+            .init_resource::<FrameThrottle>()
+            .insert_resource(CameraDrainBudget { max_frames_per_update: 3, max_queued: 100 })
+            .add_event::<NewFrameEvent>()
+            .add_systems(Update, drain_camera_queue);
+
+        for i in 0..10 {
+            queue_camera_frame(DEFAULT_CAMERA_SOURCE.to_string(), 1, 1, vec![i, i, i], 1.0 + i as f64 * 200.0, 0);
+        }
+        let mut processed = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            processed.push(app.world().resource::<CameraStats>().total_frames);
+        }
+        assert_eq!(processed, vec![3, 6, 9, 10]);
+        // Frames are handled in order, so the newest ends up in the frame resource
+        assert_eq!(app.world().resource::<CameraFrame>().0, Some(vec![9, 9, 9]));
+        assert_eq!(app.world().resource::<CameraStats>().dropped_frames, 0);
+
+        // An oversized backlog drops the oldest leftovers
+        app.insert_resource(CameraDrainBudget { max_frames_per_update: 2, max_queued: 3 });
+        for i in 0..10 {
+            queue_camera_frame(DEFAULT_CAMERA_SOURCE.to_string(), 1, 1, vec![i, i, i], 5000.0 + i as f64 * 200.0, 0);
+        }
+        app.update();
+        let stats = app.world().resource::<CameraStats>();
+        assert_eq!(stats.total_frames, 12);
+        assert_eq!(stats.dropped_frames, 5);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<CameraFrame>().0, Some(vec![9, 9, 9]));
+    }
+
+    #[test]
+    fn test_portrait_frame_is_rotated_upright() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<CameraFrame>()
+            .init_resource::<CameraSources>()
+            .init_resource::<CameraStats>()
+            .init_resource::<FrameThrottle>()
+            .add_event::<NewFrameEvent>()
+            .add_systems(Update, drain_camera_queue);
+
+        // 3x2 RGB frame, pixel value = its index (a b c / d e f)
+        let rgb: Vec<u8> = (0..6u8).flat_map(|i| [i, i, i]).collect();
+        queue_camera_frame(DEFAULT_CAMERA_SOURCE.to_string(), 3, 2, rgb, 1.0, 90);
+        app.update();
+
+        let events = app.world().resource::<Events<NewFrameEvent>>();
+        let frame = events.get_cursor().read(events).next().expect("frame emitted").clone();
+        assert_eq!((frame.width, frame.height), (2, 3));
+        // Clockwise: d a / e b / f c
+        let pixels: Vec<u8> = frame.rgba.chunks_exact(4).map(|px| px[0]).collect();
+        assert_eq!(pixels, vec![3, 0, 4, 1, 5, 2]);
+        assert!(frame.rgba.chunks_exact(4).all(|px| px[3] == 255));
+        assert_eq!(app.world().resource::<CameraStats>().last_frame_size, Some(UVec2::new(2, 3)));
+
+        // 180 keeps the size and 270 undoes 90
+        let (flipped, w, h) = rotate_rgba(frame.rgba.clone(), 2, 3, 180);
+        assert_eq!((w, h), (2, 3));
+        assert_eq!(flipped.chunks_exact(4).map(|px| px[0]).collect::<Vec<_>>(), vec![2, 5, 1, 4, 0, 3]);
+        let (back, w, h) = rotate_rgba(frame.rgba, 2, 3, 270);
+        assert_eq!((w, h), (3, 2));
+        assert_eq!(back.chunks_exact(4).map(|px| px[0]).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_out_of_order_timestamps_keep_fps_sane() {
+        let timestamps = [1000.0, 1033.0, 1066.0, 1050.0, 1099.0, 1099.0, 1132.0, 200.0, 233.0];
+        let mut fps = 0.0;
+        let mut last_ts = timestamps[0];
+        for &ts in &timestamps[1..] {
+            fps = smoothed_fps(fps, frame_interval_ms(last_ts, ts));
+            last_ts = ts;
+            assert!(fps.is_finite() && (1.0..=240.0).contains(&fps), "fps {} after ts {}", fps, ts);
+        }
+        assert!((fps - 30.3).abs() < 5.0, "fps {} should stay near the real 30 fps", fps);
+
+        assert_eq!(frame_interval_ms(1066.0, 1050.0), None);
+        assert_eq!(frame_interval_ms(1099.0, 1099.0), None);
+        assert_eq!(frame_interval_ms(10.0, 10.5), Some(MIN_FRAME_INTERVAL_MS));
+        assert_eq!(frame_interval_ms(0.0, 1000.0), None, "first frame has nothing to measure against");
+    }
+
+    #[test]
+    fn test_none_filter_passes_through() {
+        let original = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut rgba = original.clone();
+        apply_preview_filter(PreviewFilter::None, &mut rgba, 2, 1);
+        assert_eq!(rgba, original);
+    }
+
+    #[cfg(feature = "camera_sprite_preview")]
+    #[test]
+    fn test_preview_layered_above_critters() {
+        use crate::components::CRITTER_Z;
+
+        // Requests that would bury the preview under critters are lifted above them
+        let (preview_z, overlay_z) = preview_layers(20.0, 10.0);
+        assert!(preview_z > CRITTER_Z);
+        assert!(overlay_z > preview_z);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .add_event::<NewFrameEvent>()
+            .insert_resource(CameraPreviewControl { preview_z, overlay_z, ..default() })
+            .add_systems(Update, update_camera_preview_system);
+        let preview = app.world_mut().spawn((Sprite::default(), Transform::default(), Visibility::Visible)).id();
+        app.insert_resource(CameraPreviewHandle(Handle::default()))
+            .insert_resource(CameraPreviewEntity(preview));
+
+        // No frame has arrived yet; the layer is applied anyway
+        app.update();
+        let z = app.world().get::<Transform>(preview).unwrap().translation.z;
+        assert_eq!(z, preview_z);
+        assert!(z > CRITTER_Z);
+    }
+
+    #[cfg(feature = "camera_sprite_preview")]
+    #[test]
+    fn test_preview_refreshes_at_its_own_cadence() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .add_event::<NewFrameEvent>()
+            .insert_resource(CameraPreviewControl { preview_fps: 10.0, ..default() })
+            .add_systems(Update, update_camera_preview_system);
+        let handle = app.world_mut().resource_mut::<Assets<Image>>().add(Image::default());
+        let preview = app.world_mut().spawn((Sprite::default(), Transform::default(), Visibility::Visible)).id();
+        app.insert_resource(CameraPreviewHandle(handle.clone()))
+            .insert_resource(CameraPreviewEntity(preview));
+
+        // 40fps of emitted frames for one second, each tagged with its index in the red channel
+        let mut shown = Vec::new();
+        for i in 0..40u8 {
+            app.world_mut().send_event(NewFrameEvent {
+                source_id: DEFAULT_CAMERA_SOURCE.to_string(),
+                rgba: vec![i, 0, 0, 255],
+                width: 1,
+                height: 1,
+                mirror_x: false,
+                scale: 0.5,
+                ts: 1000.0 + i as f64 * 25.0,
+            });
+            app.update();
+            let red = app.world().resource::<Assets<Image>>().get(&handle).unwrap().data.as_ref().and_then(|d| d.first().copied());
+            if red != shown.last().copied().flatten() {
+                shown.push(red);
+            }
+        }
+        // Only every fourth frame (100ms apart) reached the texture
+        let expected: Vec<Option<u8>> = (0..40u8).step_by(4).map(Some).collect();
+        assert_eq!(shown, expected);
+
+        assert!(preview_frame_due(Some(2000.0), 1000.0, 10.0), "backwards clock re-baselines");
+        assert!(preview_frame_due(Some(1000.0), 1001.0, 0.0), "0 fps disables the preview throttle");
+    }
+
+    #[test]
+    fn test_posture_smoothing_suppresses_flicker() {
+        let mut state = PostureRecognitionState { smoothing_window: 5, confidence_threshold: 0.6, ..default() };
+        // Mostly "standing" with single-frame dropouts and a low-confidence "sitting" blip
+        let noisy = [
+            ("standing", 0.9), ("standing", 0.8), ("unknown", 1.0), ("standing", 0.9), ("sitting", 0.3),
+            ("standing", 0.9), ("standing", 0.8), ("standing", 0.7), ("unknown", 1.0), ("standing", 0.9),
+        ];
+        let smoothed: Vec<String> = noisy.iter().map(|(l, c)| state.push_label(l, *c).to_string()).collect();
+        // Once standing wins the vote it holds despite the noise
+        let first_standing = smoothed.iter().position(|l| l == "standing").expect("standing detected");
+        assert!(first_standing <= 2, "{:?}", smoothed);
+        assert!(smoothed[first_standing..].iter().all(|l| l == "standing"), "{:?}", smoothed);
+
+        // Sustained low confidence falls back to unknown
+        for _ in 0..5 {
+            state.push_label("standing", 0.2);
+        }
+        assert_eq!(state.last_label, UNKNOWN_POSTURE);
+    }
+}
@@ -26,6 +26,73 @@ pub struct CritterPersonality {
     pub obedience: f32,      // 0.0 - 1.0
 }
 
+/// Z layer critters are drawn at; overlays that must stay on top use higher values
+pub const CRITTER_Z: f32 = 100.0;
+
+/// When this critter was spawned (elapsed secs), for the catch grace period
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpawnedAt {
+    pub at: f64,
+}
+
+/// Calm-mode removal: the caught critter shrinks and fades over the timer, then despawns (no explosion)
+#[derive(Component)]
+pub struct FadingOut {
+    pub timer: Timer,
+    pub base_scale: Vec3,
+}
+
+/// Practice dummy: catching it scores and explodes as usual, then it respawns in place
+#[derive(Component)]
+pub struct Persistent;
+
+/// Critter held still at screen center for a tutorial overlay to point at
+#[derive(Component)]
+pub struct TutorialCritter;
+
+/// Escaping critter running for the nearest screen edge; despawned once off-screen
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Fleeing {
+    pub direction: Vec2, // unit vector toward the chosen edge
+}
+
+/// Seconds a critter has spent past the off-screen watchdog's boundary; removed once it's back in view
+#[derive(Component, Debug, Default)]
+pub struct OffScreen {
+    pub elapsed_secs: f32,
+}
+
+/// Full-screen overlay faded in and out by ModeTransition
+#[derive(Component)]
+pub struct TransitionOverlay;
+
+/// Monotonic spawn sequence number; the lowest live one is the oldest critter
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SpawnOrder {
+    pub seq: u64,
+}
+
+/// When this critter's entry sound started (elapsed secs), so a quick catch doesn't blend success into it
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EntrySoundPlayed {
+    pub at: f64,
+}
+
+/// Catalog entry sound waiting for this critter's spawn-in to finish
+#[derive(Component, Debug)]
+pub struct PendingEntrySound {
+    pub path: String,
+}
+
+/// Catalog sound held back until its timer runs out (a success sound waiting for the entry sound)
+#[derive(Component, Debug)]
+pub struct DeferredSound {
+    pub path: String,
+    pub label: &'static str,
+    pub volume: f32,
+    pub timer: Timer,
+}
+
 /// Movement component for critters
 #[derive(Component)]
 pub struct CritterMovement {
@@ -35,14 +102,65 @@ pub struct CritterMovement {
     pub target_position: Option<Vec2>,
 }
 
+/// How a sprite animation carries on past its last frame (`mode:` on a catalog animation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AnimationLoopMode {
+    #[default]
+    Loop, // 0 1 2 0 1 2 ...
+    PingPong, // 0 1 2 1 0 1 ...
+    Once, // 0 1 2 2 2 ... (holds the last frame)
+}
+
 /// Animation component for sprite sheets
 #[derive(Component)]
 pub struct SpriteAnimation {
     pub timer: Timer,
     pub frame_count: usize,
     pub current_frame: usize,
-    pub repeat: bool,
+    pub mode: AnimationLoopMode,
+    pub reversing: bool, // ping-pong is on its way back down
     pub critter_id: String, // ID to look up frame layout in CritterRegistry
+    pub animation: String, // Animation name in the catalog ("idle", "caught", ...)
+}
+
+impl SpriteAnimation {
+    /// Step to the next frame according to the loop mode
+    pub fn advance(&mut self) {
+        let last = self.frame_count.saturating_sub(1);
+        self.current_frame = match self.mode {
+            AnimationLoopMode::Loop => (self.current_frame + 1) % self.frame_count.max(1),
+            AnimationLoopMode::Once => (self.current_frame + 1).min(last),
+            AnimationLoopMode::PingPong if last == 0 => 0,
+            AnimationLoopMode::PingPong => {
+                if self.current_frame >= last {
+                    self.reversing = true;
+                } else if self.current_frame == 0 {
+                    self.reversing = false;
+                }
+                if self.reversing { self.current_frame - 1 } else { self.current_frame + 1 }
+            }
+        };
+    }
+}
+
+/// Spawn-in easing: the critter grows and fades in from nothing to `target_scale`
+#[derive(Component)]
+pub struct SpawnIn {
+    pub timer: Timer,
+    pub target_scale: Vec3,
+}
+
+/// Short "caught" reaction played before a critter is despawned and explodes
+#[derive(Component)]
+pub struct CaughtReaction {
+    pub timer: Timer,
+    pub base_scale: Vec3,
+}
+
+/// Critter currently being petted (pressed and held); completes on release
+#[derive(Component)]
+pub struct Petting {
+    pub started_at: f64, // Time::elapsed_secs_f64 at press
 }
 
 /// Interactive area component
@@ -70,6 +188,9 @@ pub struct ScoreDisplay;
 #[derive(Component)]
 pub struct LevelDisplay;
 
+#[derive(Component)]
+pub struct CountdownDisplay;
+
 /// Audio components
 #[derive(Component)]
 pub struct GameAudioSource {
@@ -1,7 +1,25 @@
 use bevy::prelude::*;
+use bevy::render::renderer::RenderAdapterInfo;
+use bevy::render::settings::Backends;
 use bevy_hanabi::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
 use web_sys::console;
 
+// Browser console on the web; native builds (tests) have no console to call into and drop the message
+#[cfg(target_arch = "wasm32")]
+macro_rules! console_log {
+    ($($t:tt)*) => (console::log_1(&format!($($t)*).into()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_log {
+    ($($t:tt)*) => ({ let _ = format_args!($($t)*); })
+}
+
+use crate::audio::{AudioManager, AudioRequest};
+use crate::resources::{AudioGate, FeedbackIntensity};
+
 /// Component to mark entities that should explode when despawned
 #[derive(Component)]
 pub struct ExplodeOnDespawn {
@@ -19,12 +37,165 @@ pub enum ExplosionType {
 pub struct CritterExplodeEvent {
     pub position: Vec3,
     pub explosion_type: ExplosionType,
+    pub critter_id: Option<String>, // catalog id of the exploding critter, picks its explosion sound
+}
+
+/// Optional sound (a registered audio id) layered under the success sound when a critter explodes
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ExplosionSoundConfig {
+    pub default_sound: Option<String>,
+    pub per_critter: HashMap<String, String>, // catalog critter id -> sound id, overrides the default
+}
+
+impl ExplosionSoundConfig {
+    /// Keeps the explosion under the critter's success sound
+    pub const LAYER_VOLUME: f32 = 0.6;
+
+    pub fn sound_for(&self, critter_id: Option<&str>) -> Option<&str> {
+        critter_id
+            .and_then(|id| self.per_critter.get(id))
+            .or(self.default_sound.as_ref())
+            .map(String::as_str)
+    }
 }
 
-/// Resource holding explosion effect assets
+/// Resource holding explosion effect assets, one per feedback intensity (the burst size is baked in)
 #[derive(Resource)]
 pub struct ExplosionEffects {
-    pub particle_explosion: Handle<EffectAsset>,
+    pub particle_explosions: [Handle<EffectAsset>; 3], // in FeedbackIntensity::ALL order
+}
+
+impl ExplosionEffects {
+    pub fn for_intensity(&self, intensity: FeedbackIntensity) -> Handle<EffectAsset> {
+        let index = FeedbackIntensity::ALL.iter().position(|level| *level == intensity).unwrap_or(2);
+        self.particle_explosions[index].clone()
+    }
+}
+
+/// Particles in one full-intensity GPU explosion burst
+#[cfg(any(feature = "webgpu", test))]
+pub const GPU_BURST_PARTICLES: usize = 600;
+
+/// Tunable explosion settings
+#[derive(Resource, Debug, Clone)]
+pub struct ExplosionConfig {
+    /// Maximum explosions allowed on screen at once; extra ones are skipped
+    pub max_concurrent: usize,
+    /// How long a single explosion stays active (matches particle lifetime)
+    pub lifetime_secs: f32,
+    /// Downward acceleration in px/s^2 (low = floaty, high = heavy)
+    pub gravity: f32,
+    /// Linear drag coefficient (high = snappy stop)
+    pub drag: f32,
+    /// Initial outward particle speed in px/s
+    pub initial_speed: f32,
+    /// Sprite particles spawned per fallback explosion
+    pub fallback_particle_count: usize,
+}
+
+impl Default for ExplosionConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 4,
+            lifetime_secs: 1.2,
+            gravity: 180.0,
+            drag: 0.8,
+            initial_speed: 150.0,
+            fallback_particle_count: 24,
+        }
+    }
+}
+
+/// Simple sprite particle used when GPU particles aren't available
+#[derive(Component)]
+pub struct FallbackParticle {
+    pub velocity: Vec2,
+    pub ttl: Timer,
+}
+
+/// Despawn timer for a spawned GPU explosion effect (the burst has no natural end)
+#[derive(Component)]
+pub struct ExplosionLifetime(pub Timer);
+
+/// Advance one fallback particle by `dt` using the configured gravity and drag.
+/// Mirrors the hanabi AccelModifier + LinearDragModifier pair.
+pub fn integrate_fallback_particle(position: Vec2, velocity: Vec2, config: &ExplosionConfig, dt: f32) -> (Vec2, Vec2) {
+    let mut velocity = velocity + Vec2::new(0.0, -config.gravity) * dt;
+    velocity *= (1.0 - config.drag * dt).max(0.0);
+    (position + velocity * dt, velocity)
+}
+
+/// Tracks explosions that are still playing so combo bursts can't exceed the cap
+#[derive(Resource, Default)]
+pub struct ActiveExplosions {
+    timers: Vec<Timer>,
+    pub skipped: u32,
+}
+
+impl ActiveExplosions {
+    pub fn count(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Reserve a slot for a new explosion; returns false when at the cap
+    pub fn try_start(&mut self, config: &ExplosionConfig) -> bool {
+        if self.timers.len() >= config.max_concurrent {
+            self.skipped += 1;
+            return false;
+        }
+        self.timers.push(Timer::from_seconds(config.lifetime_secs, TimerMode::Once));
+        true
+    }
+
+    /// Advance all active explosions, releasing the finished ones
+    pub fn tick(&mut self, delta: std::time::Duration) {
+        for timer in self.timers.iter_mut() {
+            timer.tick(delta);
+        }
+        self.timers.retain(|t| !t.finished());
+    }
+}
+
+/// Which explosion renderer runs, picked from the render adapter at startup; sprites until then
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParticleBackend {
+    #[default]
+    Sprites,
+    Gpu, // hanabi compute particles (webgpu build on a WebGPU adapter)
+}
+
+impl ParticleBackend {
+    /// GPU particles need compute shaders: only a webgpu build running on a WebGPU adapter qualifies
+    pub fn detect(webgpu_build: bool, adapter_backends: Backends) -> Self {
+        if webgpu_build && adapter_backends.contains(Backends::BROWSER_WEBGPU) {
+            ParticleBackend::Gpu
+        } else {
+            ParticleBackend::Sprites
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParticleBackend::Sprites => "sprites",
+            ParticleBackend::Gpu => "gpu",
+        }
+    }
+}
+
+/// GPU particles are picked and their effect assets are ready
+fn gpu_particles_active(backend: Res<ParticleBackend>, effects: Option<Res<ExplosionEffects>>) -> bool {
+    *backend == ParticleBackend::Gpu && effects.is_some()
+}
+
+/// Register both explosion handlers; sprites take every explosion the GPU handler can't
+pub fn add_explosion_handlers(app: &mut App) {
+    app.add_systems(Update, (
+        track_active_explosions,
+        handle_explosion_events.run_if(gpu_particles_active),
+        handle_explosion_events_fallback.run_if(not(gpu_particles_active)),
+        update_fallback_particles,
+        despawn_finished_explosions,
+    ).chain());
 }
 
 /// Plugin for explosion effects
@@ -38,25 +209,52 @@ impl Plugin for ExplosionEffectsPlugin {
         console::log_1(&"🎆 Adding CritterExplodeEvent...".into());
         app.add_event::<CritterExplodeEvent>();
         console::log_1(&"✅ CritterExplodeEvent added".into());
+        app.init_resource::<ExplosionConfig>()
+            .init_resource::<FeedbackIntensity>()
+            .init_resource::<ActiveExplosions>()
+            .init_resource::<ParticleBackend>()
+            .init_resource::<ExplosionSoundConfig>();
         
-        // Use fallback system for now due to WebGL2 vs WebGPU complexity
-        console::log_1(&"🎆 Using fallback explosion system (WebGL2 compatible)".into());
-        app.add_systems(Update, handle_explosion_events_fallback);
+        // GPU particles only exist in the webgpu build; the adapter check still picks sprites elsewhere
+        #[cfg(feature = "webgpu")]
+        app.add_plugins(HanabiPlugin)
+            .add_systems(Startup, setup_explosion_effects);
         
-        // TODO: Implement proper WebGPU detection and dual-build system
-        // For now, fallback provides working explosion events without GPU particles
+        app.add_systems(Startup, detect_particle_backend)
+            .add_systems(Update, explosion_sound_system);
+        add_explosion_handlers(app);
         
         console::log_1(&"🎆 ExplosionEffectsPlugin setup complete!".into());
     }
 }
 
+/// Pick the explosion renderer from the render adapter, and publish it for JS
+fn detect_particle_backend(adapter: Option<Res<RenderAdapterInfo>>, mut backend: ResMut<ParticleBackend>) {
+    let adapter_backends = adapter.as_ref().map_or(Backends::empty(), |adapter| Backends::from(adapter.backend));
+    *backend = ParticleBackend::detect(cfg!(feature = "webgpu"), adapter_backends);
+    crate::set_particle_backend(*backend);
+    console_log!("🎆 Explosion renderer: {} (adapter backends {:?})", backend.name(), adapter_backends);
+}
+
 /// Setup explosion effect assets
+#[cfg(feature = "webgpu")]
 fn setup_explosion_effects(
     mut effects: ResMut<Assets<EffectAsset>>,
+    config: Res<ExplosionConfig>,
     mut commands: Commands,
 ) {
     console::log_1(&"🎆 Setting up ribbon explosion effects...".into());
+
+    let particle_explosions = FeedbackIntensity::ALL
+        .map(|intensity| effects.add(build_explosion_effect(&config, intensity.particle_count(GPU_BURST_PARTICLES))));
+    commands.insert_resource(ExplosionEffects { particle_explosions });
     
+    console::log_1(&"✨ Particle explosion effect ready!".into());
+}
+
+/// Ribbon explosion effect bursting `burst_particles` at once
+#[cfg(feature = "webgpu")]
+fn build_explosion_effect(config: &ExplosionConfig, burst_particles: usize) -> EffectAsset {
     let mut module = Module::default();
 
     // Spawn positions over a small sphere for 3D-like explosion
@@ -69,11 +267,11 @@ fn setup_explosion_effects(
     // Radial velocity - particles explode outward
     let init_vel = SetVelocitySphereModifier {
         center: module.lit(Vec3::ZERO),
-        speed: module.lit(150.0), // Fast initial explosion
+        speed: module.lit(config.initial_speed), // Fast initial explosion
     };
     
     // Particle lifetime
-    let init_life = SetAttributeModifier::new(Attribute::LIFETIME, module.lit(1.2));
+    let init_life = SetAttributeModifier::new(Attribute::LIFETIME, module.lit(config.lifetime_secs));
 
     // Color gradient for ribbons - colorful pet-friendly explosion
     let mut gradient = Gradient::new();
@@ -83,13 +281,13 @@ fn setup_explosion_effects(
     gradient.add_key(1.0, Vec4::new(0.1, 0.1, 0.1, 0.0)); // Fade to transparent
 
     // Create linear drag and gravity modifiers before consuming module
-    let drag_modifier = LinearDragModifier::new(module.lit(0.8));
-    let gravity_modifier = AccelModifier::new(module.lit(Vec3::new(0.0, -180.0, 0.0)));
+    let drag_modifier = LinearDragModifier::new(module.lit(config.drag));
+    let gravity_modifier = AccelModifier::new(module.lit(Vec3::new(0.0, -config.gravity, 0.0)));
     
     // Build a dramatic particle explosion effect (no ribbons in 0.16, but still impressive!)
-    let effect = EffectAsset::new(
+    EffectAsset::new(
         2048, // Max particles for good performance on mobile
-        SpawnerSettings::burst(600.0.into(), 0.0.into()), // 600 particles instantly
+        SpawnerSettings::burst((burst_particles as f32).into(), 0.0.into()), // all particles instantly
         module,
     )
     .with_name("critter_explosion")
@@ -102,37 +300,40 @@ fn setup_explosion_effects(
         gradient,
         blend: ColorBlendMode::Overwrite,
         mask: ColorBlendMask::RGBA,
-    });
-
-    let handle = effects.add(effect);
-    commands.insert_resource(ExplosionEffects {
-        particle_explosion: handle,
-    });
-    
-    console::log_1(&"✨ Particle explosion effect ready!".into());
+    })
 }
 
 /// Handle explosion events by spawning particle effects
 fn handle_explosion_events(
     mut explosion_events: EventReader<CritterExplodeEvent>,
     explosion_effects: Res<ExplosionEffects>,
+    config: Res<ExplosionConfig>,
+    intensity: Option<Res<FeedbackIntensity>>,
+    mut active: ResMut<ActiveExplosions>,
+    mut telemetry: ResMut<crate::resources::Telemetry>,
     mut commands: Commands,
 ) {
     // DEBUG: Log when explosion events are received
     let event_count = explosion_events.len();
     if event_count > 0 {
-        console::log_1(&format!("🎆 Processing {} explosion events", event_count).into());
+        console_log!("🎆 Processing {} explosion events", event_count);
     }
     
     for event in explosion_events.read() {
         match event.explosion_type {
             ExplosionType::ParticleBurst => {
-                console::log_1(&format!("🎆 Spawning particle explosion at ({:.1}, {:.1}, {:.1})", 
-                    event.position.x, event.position.y, event.position.z).into());
+                if !active.try_start(&config) {
+                    console_log!("🎆 Explosion skipped: {} already active (cap {})", active.count(), config.max_concurrent);
+                    continue;
+                }
+                telemetry.explosions += 1;
+                console_log!("🎆 Spawning particle explosion at ({:.1}, {:.1}, {:.1})", 
+                    event.position.x, event.position.y, event.position.z);
                 
                 commands.spawn((
-                    ParticleEffect::new(explosion_effects.particle_explosion.clone()),
+                    ParticleEffect::new(explosion_effects.for_intensity(intensity.as_deref().copied().unwrap_or_default())),
                     Transform::from_translation(event.position),
+                    ExplosionLifetime(Timer::from_seconds(config.lifetime_secs, TimerMode::Once)),
                 ));
             }
         }
@@ -142,35 +343,484 @@ fn handle_explosion_events(
 /// Fallback explosion handler for WebGL/incompatible hardware
 fn handle_explosion_events_fallback(
     mut explosion_events: EventReader<CritterExplodeEvent>,
+    config: Res<ExplosionConfig>,
+    intensity: Option<Res<FeedbackIntensity>>,
+    mut active: ResMut<ActiveExplosions>,
+    mut telemetry: ResMut<crate::resources::Telemetry>,
     mut commands: Commands,
 ) {
     // DEBUG: Log when explosion events are received  
     let event_count = explosion_events.len();
     if event_count > 0 {
-        console::log_1(&format!("🎆 Processing {} explosion events (FALLBACK)", event_count).into());
+        console_log!("🎆 Processing {} explosion events (FALLBACK)", event_count);
     }
     
     for event in explosion_events.read() {
         match event.explosion_type {
             ExplosionType::ParticleBurst => {
-                console::log_1(&format!("🎆 FALLBACK: Simple explosion effect at ({:.1}, {:.1}, {:.1})", 
-                    event.position.x, event.position.y, event.position.z).into());
+                if !active.try_start(&config) {
+                    console_log!("🎆 FALLBACK: Explosion skipped: {} already active (cap {})", active.count(), config.max_concurrent);
+                    continue;
+                }
+                telemetry.explosions += 1;
+                console_log!("🎆 FALLBACK: Sprite particle explosion at ({:.1}, {:.1}, {:.1})", 
+                    event.position.x, event.position.y, event.position.z);
                 
-                // TODO: Add simple sprite-based explosion effect
-                // For now, just log that the explosion happened
-                console::log_1(&"✨ Fallback explosion complete! (No GPU particles, but critter still despawns)".into());
+                // Colors follow the GPU gradient: yellow-orange, pink-purple, blue
+                let palette = [
+                    Color::srgb(1.0, 0.8, 0.2),
+                    Color::srgb(0.9, 0.4, 0.8),
+                    Color::srgb(0.2, 0.6, 1.0),
+                ];
+                let mut rng = thread_rng();
+                let particle_count = intensity.as_deref().copied().unwrap_or_default().particle_count(config.fallback_particle_count);
+                for i in 0..particle_count {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let speed = config.initial_speed * rng.gen_range(0.5..1.0);
+                    commands.spawn((
+                        Sprite {
+                            color: palette[i % palette.len()],
+                            custom_size: Some(Vec2::splat(8.0)),
+                            ..default()
+                        },
+                        Transform::from_translation(event.position.truncate().extend(150.0)),
+                        FallbackParticle {
+                            velocity: Vec2::new(angle.cos(), angle.sin()) * speed,
+                            ttl: Timer::from_seconds(config.lifetime_secs, TimerMode::Once),
+                        },
+                    ));
+                }
             }
         }
     }
 }
 
+/// Move and fade fallback sprite particles using the shared gravity/drag settings
+fn update_fallback_particles(
+    time: Res<Time>,
+    config: Res<ExplosionConfig>,
+    mut particles: Query<(&mut Transform, &mut FallbackParticle, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut particle, mut sprite) in &mut particles {
+        particle.ttl.tick(time.delta());
+        let (position, velocity) = integrate_fallback_particle(transform.translation.truncate(), particle.velocity, &config, dt);
+        particle.velocity = velocity;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        sprite.color.set_alpha(1.0 - particle.ttl.fraction());
+    }
+}
+
+/// Despawn explosion effects and fallback particles once their lifetime is over
+fn despawn_finished_explosions(
+    time: Res<Time>,
+    mut effects: Query<(Entity, &mut ExplosionLifetime)>,
+    particles: Query<(Entity, &FallbackParticle)>,
+    mut commands: Commands,
+) {
+    for (entity, mut lifetime) in &mut effects {
+        if lifetime.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+    // Fallback particle ttl is ticked in update_fallback_particles
+    for (entity, particle) in &particles {
+        if particle.ttl.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Release explosion slots once their lifetime has elapsed
+fn track_active_explosions(
+    time: Res<Time>,
+    mut active: ResMut<ActiveExplosions>,
+) {
+    active.tick(time.delta());
+}
+
+/// Fading sprite left along a swipe path
+#[derive(Component)]
+pub struct SwipeTrail {
+    pub fade: Timer,
+}
+
+/// Request a swipe trail; `direction` is the swipe delta in world space
+#[derive(Event, Debug, Clone)]
+pub struct SwipeTrailEvent {
+    pub origin: Vec2,
+    pub direction: Vec2,
+}
+
+/// Swipe trail look and toggle
+#[derive(Resource, Debug, Clone)]
+pub struct SwipeTrailConfig {
+    pub enabled: bool,
+    /// Sprites spawned per swipe, evenly spaced along the path
+    pub segments: usize,
+    /// Lifetime of the segment at the swipe's end; earlier segments fade sooner
+    pub lifetime_secs: f32,
+    /// Longest trail drawn, in px (long flicks are shortened, keeping direction)
+    pub max_length: f32,
+    pub size: f32,
+}
+
+impl Default for SwipeTrailConfig {
+    fn default() -> Self {
+        Self { enabled: true, segments: 6, lifetime_secs: 0.3, max_length: 220.0, size: 14.0 }
+    }
+}
+
+/// Plugin for swipe feedback trails
+pub struct SwipeTrailPlugin;
+
+impl Plugin for SwipeTrailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SwipeTrailEvent>()
+            .init_resource::<SwipeTrailConfig>()
+            .add_systems(Update, (spawn_swipe_trails, update_swipe_trails).chain());
+    }
+}
+
+/// Lay fading sprites from the swipe origin along its direction
+fn spawn_swipe_trails(
+    mut events: EventReader<SwipeTrailEvent>,
+    config: Res<SwipeTrailConfig>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        if !config.enabled || config.segments == 0 {
+            continue;
+        }
+        let path = event.direction.clamp_length_max(config.max_length);
+        let steps = config.segments.saturating_sub(1).max(1) as f32;
+        for i in 0..config.segments {
+            let t = i as f32 / steps;
+            let lifetime = config.lifetime_secs * (0.5 + 0.5 * t);
+            let size = config.size * (0.5 + 0.5 * t);
+            commands.spawn((
+                Sprite {
+                    color: Color::srgba(1.0, 1.0, 1.0, 0.8),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                // Just above critters so the trail reads even when swiping across one
+                Transform::from_translation((event.origin + path * t).extend(crate::components::CRITTER_Z + 1.0)),
+                SwipeTrail { fade: Timer::from_seconds(lifetime, TimerMode::Once) },
+            ));
+        }
+    }
+}
+
+/// Fade trail sprites out and despawn them when their timer ends
+fn update_swipe_trails(
+    time: Res<Time>,
+    mut trails: Query<(Entity, &mut SwipeTrail, &mut Sprite)>,
+    mut commands: Commands,
+) {
+    for (entity, mut trail, mut sprite) in &mut trails {
+        if trail.fade.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        } else {
+            sprite.color.set_alpha(0.8 * (1.0 - trail.fade.fraction()));
+        }
+    }
+}
+
 /// Trigger explosion for a critter at given position
 pub fn trigger_critter_explosion(
     position: Vec3,
+    critter_id: Option<&str>,
     explosion_events: &mut EventWriter<CritterExplodeEvent>,
 ) {
     explosion_events.write(CritterExplodeEvent {
         position,
         explosion_type: ExplosionType::ParticleBurst,
+        critter_id: critter_id.map(str::to_string),
     });
-}
\ No newline at end of file
+}
+
+/// Play the configured explosion sound for each explosion, once audio is unlocked
+fn explosion_sound_system(
+    mut explosion_events: EventReader<CritterExplodeEvent>,
+    config: Res<ExplosionSoundConfig>,
+    audio_gate: Res<AudioGate>,
+    audio_manager: Res<AudioManager>,
+    intensity: Option<Res<FeedbackIntensity>>,
+    mut audio_requests: EventWriter<AudioRequest>,
+    mut sequence: Local<u32>,
+) {
+    let volume_scale = intensity.as_deref().copied().unwrap_or_default().volume_scale();
+    for event in explosion_events.read() {
+        if !audio_gate.enabled {
+            continue;
+        }
+        let Some(sound_id) = config.sound_for(event.critter_id.as_deref()) else { continue };
+        let (Some(info), Some(volume)) = (
+            audio_manager.sound_registry.get(sound_id),
+            audio_manager.effective_volume(sound_id, None),
+        ) else {
+            continue;
+        };
+        *sequence += 1;
+        audio_requests.write(AudioRequest::Play {
+            request_id: format!("audio-explosion-{}", *sequence),
+            sound_id: sound_id.to_string(),
+            context: info.context.clone(),
+            volume: volume * ExplosionSoundConfig::LAYER_VOLUME * volume_scale,
+            loop_audio: false,
+            source_url: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_swipe_spawns_trail_that_fades_away() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .add_plugins(SwipeTrailPlugin);
+        let count_trails = |app: &mut App| app.world_mut().query::<&SwipeTrail>().iter(app.world()).count();
+
+        app.world_mut().send_event(SwipeTrailEvent { origin: Vec2::ZERO, direction: Vec2::new(500.0, 0.0) });
+        app.update();
+        let segments = app.world().resource::<SwipeTrailConfig>().segments;
+        assert_eq!(count_trails(&mut app), segments);
+        // Long flicks are capped to the configured trail length
+        let max_length = app.world().resource::<SwipeTrailConfig>().max_length;
+        let furthest = app.world_mut().query_filtered::<&Transform, With<SwipeTrail>>()
+            .iter(app.world())
+            .map(|tf| tf.translation.x)
+            .fold(0.0, f32::max);
+        assert!((furthest - max_length).abs() < 0.01);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(count_trails(&mut app), 0);
+
+        // Disabled trails spawn nothing
+        app.world_mut().resource_mut::<SwipeTrailConfig>().enabled = false;
+        app.world_mut().send_event(SwipeTrailEvent { origin: Vec2::ZERO, direction: Vec2::X * 100.0 });
+        app.update();
+        assert_eq!(count_trails(&mut app), 0);
+    }
+
+    #[test]
+    fn test_explosion_handlers_follow_the_particle_backend() {
+        assert_eq!(ParticleBackend::detect(true, Backends::BROWSER_WEBGPU), ParticleBackend::Gpu);
+        assert_eq!(ParticleBackend::detect(true, Backends::GL), ParticleBackend::Sprites);
+        assert_eq!(ParticleBackend::detect(false, Backends::BROWSER_WEBGPU), ParticleBackend::Sprites);
+
+        // Without a render adapter (headless) detection settles on sprites
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(ParticleBackend::Gpu)
+            .add_systems(Startup, detect_particle_backend);
+        app.update();
+        assert_eq!(*app.world().resource::<ParticleBackend>(), ParticleBackend::Sprites);
+
+        // A Gpu pick whose effect assets never got built still shows sprite explosions
+        for (backend, effects_ready, expect_gpu) in [
+            (ParticleBackend::Gpu, true, true),
+            (ParticleBackend::Gpu, false, false),
+            (ParticleBackend::Sprites, true, false),
+        ] {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins)
+                .insert_resource(backend)
+                .init_resource::<ExplosionConfig>()
+                .init_resource::<ActiveExplosions>()
+                .init_resource::<crate::resources::Telemetry>()
+                .add_event::<CritterExplodeEvent>();
+            if effects_ready {
+                app.insert_resource(ExplosionEffects { particle_explosions: Default::default() });
+            }
+            add_explosion_handlers(&mut app);
+
+            app.world_mut().send_event(CritterExplodeEvent {
+                position: Vec3::ZERO,
+                explosion_type: ExplosionType::ParticleBurst,
+                critter_id: None,
+            });
+            app.update();
+
+            let world = app.world_mut();
+            let bursts = world.query::<&ExplosionLifetime>().iter(world).count();
+            let sprites = world.query::<&FallbackParticle>().iter(world).count();
+            if expect_gpu {
+                assert_eq!((bursts, sprites), (1, 0));
+            } else {
+                assert!(bursts == 0 && sprites > 0, "{:?}: {} sprite particles", backend, sprites);
+            }
+            assert_eq!(world.resource::<crate::resources::Telemetry>().explosions, 1, "{:?}", backend);
+        }
+    }
+
+    #[test]
+    fn test_feedback_intensity_scales_particles_and_volume() {
+        let base = ExplosionConfig::default().fallback_particle_count;
+        let counts = FeedbackIntensity::ALL.map(|level| level.particle_count(base));
+        assert_eq!(counts, [5, 12, base]);
+        assert_eq!(FeedbackIntensity::ALL.map(|level| level.particle_count(GPU_BURST_PARTICLES)), [120, 300, 600]);
+        assert_eq!(FeedbackIntensity::Low.particle_count(1), 1, "a catch always shows something");
+        assert_eq!(FeedbackIntensity::default(), FeedbackIntensity::High);
+        assert_eq!(FeedbackIntensity::parse("Medium"), Ok(FeedbackIntensity::Medium));
+        assert!(FeedbackIntensity::parse("loud").is_err());
+
+        let mut audio_manager = AudioManager::default();
+        audio_manager.sound_registry.insert("boom".to_string(), crate::audio::AudioFileInfo {
+            file_path: "assets/audio/effects/boom.ogg".to_string(),
+            context: crate::audio::AudioContext::Critter,
+            default_volume: 1.0,
+            format: crate::audio::AudioFormat::Ogg,
+            alternatives: Vec::new(),
+        });
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(audio_manager)
+            .insert_resource(ExplosionSoundConfig { default_sound: Some("boom".to_string()), ..default() })
+            .insert_resource(AudioGate { enabled: true, ..Default::default() })
+            .init_resource::<FeedbackIntensity>()
+            .add_event::<CritterExplodeEvent>()
+            .add_event::<AudioRequest>()
+            .add_systems(Update, explosion_sound_system);
+        let mut cursor = app.world().resource::<Events<AudioRequest>>().get_cursor();
+        let mut volumes = Vec::new();
+        for level in FeedbackIntensity::ALL {
+            app.insert_resource(level);
+            app.world_mut().send_event(CritterExplodeEvent {
+                position: Vec3::ZERO,
+                explosion_type: ExplosionType::ParticleBurst,
+                critter_id: None,
+            });
+            app.update();
+            let events = app.world().resource::<Events<AudioRequest>>();
+            volumes.extend(cursor.read(events).filter_map(|r| match r {
+                AudioRequest::Play { volume, .. } => Some(*volume),
+                _ => None,
+            }));
+        }
+        let layer = ExplosionSoundConfig::LAYER_VOLUME;
+        assert_eq!(volumes, vec![layer * 0.35, layer * 0.7, layer]);
+    }
+
+    #[test]
+    fn test_explosion_sound_is_requested() {
+        let mut audio_manager = AudioManager::default();
+        audio_manager.sound_registry.insert("boom".to_string(), crate::audio::AudioFileInfo {
+            file_path: "assets/audio/effects/boom.ogg".to_string(),
+            context: crate::audio::AudioContext::Critter,
+            default_volume: 1.0,
+            format: crate::audio::AudioFormat::Ogg,
+            alternatives: Vec::new(),
+        });
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(audio_manager)
+            .insert_resource(ExplosionSoundConfig { default_sound: Some("boom".to_string()), ..default() })
+            .insert_resource(AudioGate { enabled: false, ..Default::default() })
+            .add_event::<CritterExplodeEvent>()
+            .add_event::<AudioRequest>()
+            .add_systems(Update, explosion_sound_system);
+        let mut cursor = app.world().resource::<Events<AudioRequest>>().get_cursor();
+        let mut played = |app: &App| -> Vec<(String, f32)> {
+            let events = app.world().resource::<Events<AudioRequest>>();
+            cursor.read(events).filter_map(|r| match r {
+                AudioRequest::Play { sound_id, volume, .. } => Some((sound_id.clone(), *volume)),
+                _ => None,
+            }).collect()
+        };
+        let explode = |app: &mut App, critter_id: Option<&str>| {
+            app.world_mut().send_event(CritterExplodeEvent {
+                position: Vec3::ZERO,
+                explosion_type: ExplosionType::ParticleBurst,
+                critter_id: critter_id.map(str::to_string),
+            });
+            app.update();
+        };
+
+        // Silent until a user gesture unlocks audio
+        explode(&mut app, Some("bunny"));
+        assert!(played(&app).is_empty());
+
+        app.world_mut().resource_mut::<AudioGate>().enabled = true;
+        explode(&mut app, Some("bunny"));
+        assert_eq!(played(&app), vec![("boom".to_string(), ExplosionSoundConfig::LAYER_VOLUME)]);
+
+        // Per-critter overrides must be registered sounds; unknown ids stay silent
+        app.world_mut().resource_mut::<ExplosionSoundConfig>().per_critter.insert("bunny".to_string(), "missing".to_string());
+        explode(&mut app, Some("bunny"));
+        assert!(played(&app).is_empty());
+        explode(&mut app, Some("pup"));
+        assert_eq!(played(&app).len(), 1);
+        assert_eq!(ExplosionSoundConfig::default().sound_for(Some("pup")), None);
+    }
+
+    #[test]
+    fn test_finished_explosions_are_despawned() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .insert_resource(ExplosionConfig { lifetime_secs: 0.5, ..default() })
+            .add_systems(Update, (update_fallback_particles, despawn_finished_explosions).chain());
+
+        let effect = app.world_mut().spawn(ExplosionLifetime(Timer::from_seconds(0.5, TimerMode::Once))).id();
+        let particle = app.world_mut().spawn((
+            Sprite::default(),
+            Transform::default(),
+            FallbackParticle { velocity: Vec2::new(10.0, 0.0), ttl: Timer::from_seconds(0.5, TimerMode::Once) },
+        )).id();
+
+        // First update only starts the clock; still alive before the lifetime elapses
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().get_entity(effect).is_ok());
+        assert!(app.world().get_entity(particle).is_ok());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(effect).is_err());
+        assert!(app.world().get_entity(particle).is_err());
+    }
+
+    #[test]
+    fn test_active_explosions_never_exceed_cap() {
+        let config = ExplosionConfig { max_concurrent: 3, lifetime_secs: 1.0, ..default() };
+        let mut active = ActiveExplosions::default();
+
+        let started = (0..10).filter(|_| active.try_start(&config)).count();
+        assert_eq!(started, 3);
+        assert_eq!(active.count(), 3);
+        assert_eq!(active.skipped, 7);
+
+        // Slots free up once the explosions finish
+        active.tick(Duration::from_secs_f32(1.1));
+        assert_eq!(active.count(), 0);
+        assert!(active.try_start(&config));
+    }
+
+    #[test]
+    fn test_fallback_particles_use_configured_physics() {
+        let floaty = ExplosionConfig { gravity: 10.0, drag: 0.0, ..default() };
+        let heavy = ExplosionConfig { gravity: 400.0, drag: 0.0, ..default() };
+        let (_, v_floaty) = integrate_fallback_particle(Vec2::ZERO, Vec2::ZERO, &floaty, 0.1);
+        let (_, v_heavy) = integrate_fallback_particle(Vec2::ZERO, Vec2::ZERO, &heavy, 0.1);
+        assert!((v_floaty.y + 1.0).abs() < 1e-5);
+        assert!((v_heavy.y + 40.0).abs() < 1e-4);
+
+        // Drag slows particles proportionally to the configured coefficient
+        let snappy = ExplosionConfig { gravity: 0.0, drag: 5.0, ..default() };
+        let (_, v) = integrate_fallback_particle(Vec2::ZERO, Vec2::new(100.0, 0.0), &snappy, 0.1);
+        assert!((v.x - 50.0).abs() < 1e-4);
+    }
+}
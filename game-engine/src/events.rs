@@ -59,6 +59,32 @@ pub enum BevyToJsEvent {
     CameraStop {
         request_id: String,
     },
+    /// An asset (e.g. the selected critter's sprite sheet) failed to load
+    AssetLoadFailed {
+        url: String,
+        error: String,
+        /// Set when the asset is a critter sprite
+        critter_id: Option<String>,
+    },
+    /// Score changed; lets the frontend animate its own counter
+    ScoreChanged {
+        delta: i32,
+        total: u32,
+        level: u32,
+    },
+    /// An escaped critter cost a life; `lives_left` 0 means the game just ended
+    LifeLost {
+        lives_left: u32,
+    },
+    /// A timed session's countdown ran out and the game ended
+    TimeUp {
+        final_score: u32,
+    },
+    /// Engine cannot continue normally (e.g. code "no_critters" for an empty catalog)
+    EngineError {
+        code: String,
+        message: String,
+    },
 }
 
 /// Events that TypeScript sends back to Bevy
@@ -168,6 +194,7 @@ pub fn handle_js_to_bevy_events(
     mut pending_requests: ResMut<PendingRequests>,
     mut shared_settings: ResMut<SharedSettings>,
     mut preview_control: Option<ResMut<crate::camera::CameraPreviewControl>>,
+    music_policy: Res<MusicPolicy>,
 ) {
     for event in js_to_bevy_events.read() {
         match event {
@@ -205,14 +232,13 @@ pub fn handle_js_to_bevy_events(
                 // This will be handled by the audio system
             }
             JsToBevyEvent::SettingsUpdated { request_id, settings } => {
-                // Update settings resource but FORCE music_enabled to false
-                let mut updated_settings = settings.clone();
-                updated_settings.music_enabled = false; // TODO: Temporarily force music off
-                *shared_settings = updated_settings;
+                // Update settings resource; music stays off unless explicitly allowed
+                *shared_settings = apply_music_policy(settings, &music_policy);
                 console_log!(
-                    "⚙️ Settings updated ({}): music_enabled={} (FORCED OFF), bgm_volume={}, sfx_volume={}",
+                    "⚙️ Settings updated ({}): music_enabled={}{}, bgm_volume={}, sfx_volume={}",
                     request_id,
                     shared_settings.music_enabled,
+                    if music_policy.allow_music { "" } else { " (FORCED OFF)" },
                     shared_settings.bgm_volume,
                     shared_settings.sfx_volume
                 );
@@ -244,11 +270,46 @@ pub fn handle_js_to_bevy_events(
     }
 }
 
-/// Send event to JavaScript via CustomEvent
+/// Host callback for outgoing events, called with (channel, json); channel is the CustomEvent name it replaces
+pub(crate) type EventSink = std::rc::Rc<dyn Fn(&str, &str)>;
+
+thread_local! {
+    static EVENT_CALLBACK: std::cell::RefCell<Option<EventSink>> = std::cell::RefCell::new(None);
+}
+
+pub(crate) fn set_event_sink(sink: Option<EventSink>) {
+    EVENT_CALLBACK.with(|slot| *slot.borrow_mut() = sink);
+}
+
+/// Hand an outgoing event to the registered callback; false when none is set (fall back to CustomEvent)
+pub(crate) fn deliver_to_callback(channel: &str, json: &str) -> bool {
+    // Cloned out so the callback may re-register itself without a RefCell double borrow
+    let Some(sink) = EVENT_CALLBACK.with(|slot| slot.borrow().clone()) else { return false };
+    sink(channel, json);
+    true
+}
+
+/// Receive engine events through `callback(channel, json)` instead of window CustomEvents
+/// ("bevy-to-js-event", "bevy-audio-request"); pass null to go back to CustomEvents
+#[wasm_bindgen]
+pub fn set_event_callback(callback: Option<js_sys::Function>) {
+    set_event_sink(callback.map(|callback| -> EventSink {
+        std::rc::Rc::new(move |channel: &str, json: &str| {
+            if let Err(e) = callback.call2(&JsValue::NULL, &JsValue::from_str(channel), &JsValue::from_str(json)) {
+                console_error!("Event callback threw for {}: {:?}", channel, e);
+            }
+        })
+    }));
+}
+
+/// Send event to JavaScript via the registered callback, or a CustomEvent when none is set
 fn send_event_to_js(event: &BevyToJsEvent) -> Result<(), JsValue> {
-    let window = web_sys::window().ok_or("No window object")?;
     let event_data = serde_json::to_string(event)
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+    if deliver_to_callback("bevy-to-js-event", &event_data) {
+        return Ok(());
+    }
+    let window = web_sys::window().ok_or("No window object")?;
     
     let custom_event = CustomEvent::new_with_event_init_dict(
         "bevy-to-js-event",
@@ -303,6 +364,7 @@ impl Plugin for EventBridgePlugin {
             .add_event::<BevyToJsEvent>()
             .add_event::<JsToBevyEvent>()
             .init_resource::<SharedSettings>()
+            .init_resource::<MusicPolicy>()
             .init_resource::<PendingRequests>()
             .add_systems(Update, (
                 poll_js_events,
@@ -318,6 +380,27 @@ pub struct SharedSettings {
     pub music_enabled: bool,
     pub bgm_volume: f32,
     pub sfx_volume: f32,
+    // Per-context mix from the settings panel, on top of sfx_volume; None leaves that context's bus alone
+    #[serde(default)]
+    pub ui_volume: Option<f32>,
+    #[serde(default)]
+    pub critter_volume: Option<f32>,
+    #[serde(default)]
+    pub ambient_volume: Option<f32>,
+}
+
+impl SharedSettings {
+    /// Bus volume (0..1) the settings carry for an audio context, if any
+    pub fn context_volume(&self, context: &crate::audio::AudioContext) -> Option<f32> {
+        use crate::audio::AudioContext;
+        let specific = match context {
+            AudioContext::UI => self.ui_volume,
+            AudioContext::Critter => self.critter_volume,
+            AudioContext::Ambient => self.ambient_volume,
+            AudioContext::Enter | AudioContext::Exit | AudioContext::Test => None,
+        };
+        specific.map(|volume| volume.clamp(0.0, 1.0))
+    }
 }
 
 impl Default for SharedSettings {
@@ -326,16 +409,62 @@ impl Default for SharedSettings {
             music_enabled: false, // TODO: Temporarily disabled - was: true
             bgm_volume: 0.6,
             sfx_volume: 0.8,
+            ui_volume: None,
+            critter_volume: None,
+            ambient_volume: None,
         }
     }
 }
 
+/// Runtime override for the forced music-off workaround (off by default = music stays disabled)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MusicPolicy {
+    pub allow_music: bool,
+}
+
+/// Settings as they should be stored, with music forced off unless the policy allows it
+pub fn apply_music_policy(settings: &SharedSettings, policy: &MusicPolicy) -> SharedSettings {
+    let mut updated = settings.clone();
+    if !policy.allow_music {
+        updated.music_enabled = false; // TODO: Temporarily force music off
+    }
+    updated
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use bevy::app::AppExit;
     use std::time::Duration;
 
+    #[test]
+    fn test_registered_callback_receives_dispatched_events() {
+        let received: std::rc::Rc<std::cell::RefCell<Vec<(String, String)>>> = Default::default();
+        let sink_log = received.clone();
+        set_event_sink(Some(std::rc::Rc::new(move |channel: &str, json: &str| {
+            sink_log.borrow_mut().push((channel.to_string(), json.to_string()));
+        })));
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<BevyToJsEvent>()
+            .init_resource::<PendingRequests>()
+            .add_systems(Update, dispatch_bevy_to_js_events);
+        app.world_mut().send_event(BevyToJsEvent::TestEvent {
+            request_id: "cb-1".to_string(),
+            message: "hello".to_string(),
+        });
+        app.update();
+        set_event_sink(None);
+
+        let received = received.borrow();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].0, "bevy-to-js-event");
+        let event: BevyToJsEvent = serde_json::from_str(&received[0].1).unwrap();
+        assert!(matches!(event, BevyToJsEvent::TestEvent { request_id, .. } if request_id == "cb-1"));
+        assert!(!deliver_to_callback("bevy-to-js-event", "{}"), "unregistered again");
+    }
+
     #[test]
     fn test_audio_event_serialization() {
         let event = BevyToJsEvent::PlayAudio {
@@ -421,4 +550,17 @@ mod tests {
         let pending = app.world().resource::<PendingRequests>();
         assert!(!pending.audio_requests.contains_key("integration-test"));
     }
+
+    #[test]
+    fn test_music_override_honors_settings() {
+        let incoming = SharedSettings { music_enabled: true, bgm_volume: 0.5, sfx_volume: 0.7, ..Default::default() };
+
+        // Safe default keeps music forced off
+        let forced = apply_music_policy(&incoming, &MusicPolicy::default());
+        assert!(!forced.music_enabled);
+        assert_eq!(forced.bgm_volume, 0.5);
+
+        let allowed = apply_music_policy(&incoming, &MusicPolicy { allow_music: true });
+        assert!(allowed.music_enabled);
+    }
 }
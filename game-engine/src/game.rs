@@ -7,16 +7,7 @@ pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app
-            // Resources
-            .init_resource::<GameState>()
-            .init_resource::<RegistryLoadStatus>()
-            .init_resource::<SelectedCritterAsset>()
-            .init_resource::<AudioGate>()
-            // CritterRegistry must be loaded properly with real data - no Default fallback!
-            .init_resource::<AssetCollection>()
-            .init_resource::<GameConfig>()
-            
+        register_game_state(app)
             // Startup systems
             .add_systems(Startup, (
                 setup_camera,
@@ -30,7 +21,7 @@ impl Plugin for GamePlugin {
                 try_initialize_registry_from_cache,
                 critter_loading_system,
                 critter_spawning_system,
-                auto_spawn_system,
+                auto_spawn_system.run_if(spawning_enabled),
                 critter_movement_system,
                 critter_interaction_system,
                 sprite_animation_system,
@@ -38,16 +29,86 @@ impl Plugin for GamePlugin {
                 ui_update_system,
                 window_resize_system,
                 monitor_asset_loading,
+                apply_time_scale_system,
+                current_critter_snapshot_system,
+                session_playback_system,
+                caught_reaction_system,
+                fade_out_system,
+                spawn_in_system,
+                apply_ui_config_system,
+                celebration_system,
+                petting_system,
             ))
-            
-            // Events
-            .add_event::<CritterInteractionEvent>()
-            .add_event::<GameProgressEvent>()
-            .add_event::<SpawnCritterEvent>()
-            .add_event::<LoadCritterEvent>();
+            .add_systems(Update, (
+                pixel_perfect_sampler_system,
+                apply_camera_view_system,
+                critter_positions_snapshot_system,
+                (tutorial_system, practice_system, critter_switch_system).chain().before(spawn_rotation_system),
+                (cancel_interaction_system, dwell_system).chain().before(critter_interaction_system).before(petting_system),
+                spawn_rotation_system.before(critter_spawning_system),
+                critter_cap_system.before(critter_spawning_system).after(spawn_rotation_system),
+                scene_snapshot_system,
+                (unlocked_critters_snapshot_system, catalog_summary_snapshot_system),
+                catalog_status_snapshot_system,
+                mode_transition_system,
+                spawn_sound_system.after(critter_spawning_system),
+                audio_unlock_system.after(critter_interaction_system).before(crate::audio::dispatch_audio_requests),
+                (escape_timeout_system, lives_system, critter_escape_system, fleeing_system).chain(),
+                countdown_system.after(lives_system),
+                import_scene_system,
+                rescale_critters_on_resize_system.after(window_resize_system),
+                (catalog_reload_system, registry_init_timeout_system.after(try_initialize_registry_from_cache)),
+                (prune_removed_critters_system, offscreen_watchdog_system.after(critter_movement_system)),
+                (deferred_sound_system, pending_entry_sound_system, catalog_sound_system).chain().after(spawn_in_system),
+            ));
     }
 }
 
+/// Resources and events the gameplay systems expect (shared with the headless test harness)
+pub(crate) fn register_game_state(app: &mut App) -> &mut App {
+    app
+        // Resources
+        .init_resource::<GameState>()
+        .init_resource::<RegistryLoadStatus>()
+        .init_resource::<SelectedCritterAsset>()
+        .init_resource::<AudioGate>()
+        // CritterRegistry must be loaded properly with real data - no Default fallback!
+        .init_resource::<AssetCollection>()
+        .init_resource::<GameConfig>()
+        .init_resource::<AssistConfig>()
+        .init_resource::<RecentTap>()
+        .init_resource::<GameRng>()
+        .init_resource::<SessionRecorder>()
+        .init_resource::<UiConfig>()
+        .init_resource::<PixelPerfect>()
+        .init_resource::<CameraView>()
+        .init_resource::<Telemetry>()
+        .init_resource::<SpawnRotation>()
+        .init_resource::<ModeTransition>()
+        .init_resource::<EscapeConfig>()
+        .init_resource::<SpawnSoundConfig>()
+        .init_resource::<TutorialState>()
+        .init_resource::<PracticeState>()
+        .init_resource::<CritterSwitch>()
+        .init_resource::<Countdown>()
+        .init_resource::<Heatmap>()
+        .init_resource::<WarningThrottle>()
+        .init_resource::<FeedbackIntensity>()
+        .init_resource::<DespawnStyleConfig>()
+        .init_resource::<DwellConfig>()
+        .init_resource::<DwellTracker>()
+        .init_resource::<InteractionCancel>()
+
+        // Events
+        .add_event::<CritterInteractionEvent>()
+        .add_event::<GameProgressEvent>()
+        .add_event::<SpawnCritterEvent>()
+        .add_event::<CelebrateEvent>()
+        .add_event::<CritterEscapedEvent>()
+        .add_event::<PlayCatalogSoundEvent>()
+        .add_event::<LoadCritterEvent>()
+}
+
 #[derive(Resource, Default)]
 pub struct GameState {
     pub score: u32,
@@ -56,15 +117,70 @@ pub struct GameState {
     pub is_paused: bool,
     pub game_mode: GameMode,
     pub selected_critter_id: Option<String>, // Critter ID from CritterRegistry
+    pub lives: Option<u32>, // lives left this game; None = unlimited
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameMode {
     #[default]
     Menu,
     Playing,
     Paused,
     GameOver,
+    Demo,
+    Training,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 6] = [
+        GameMode::Menu,
+        GameMode::Playing,
+        GameMode::Paused,
+        GameMode::GameOver,
+        GameMode::Demo,
+        GameMode::Training,
+    ];
+
+    /// Parse a mode name from JS ("menu", "playing", "paused", "game_over", "demo", "training")
+    pub fn parse(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.name() == name.to_ascii_lowercase())
+            .ok_or_else(|| format!("Unknown game mode: '{}' (expected one of {})", name,
+                Self::ALL.map(|mode| mode.name()).join(", ")))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Menu => "menu",
+            GameMode::Playing => "playing",
+            GameMode::Paused => "paused",
+            GameMode::GameOver => "game_over",
+            GameMode::Demo => "demo",
+            GameMode::Training => "training",
+        }
+    }
+
+    /// Only live play auto-spawns critters
+    pub fn spawns_critters(&self) -> bool {
+        *self == GameMode::Playing
+    }
+}
+
+impl GameState {
+    /// Switch modes, keeping the legacy pause flag in step
+    pub fn set_mode(&mut self, mode: GameMode) {
+        self.game_mode = mode;
+        self.is_paused = mode == GameMode::Paused;
+    }
+}
+
+/// Run condition gating automatic critter spawns on the current mode and the auto-spawn switch (and pausing
+/// them for a tutorial)
+pub fn spawning_enabled(game_state: Res<GameState>, tutorial: Option<Res<TutorialState>>, game_config: Option<Res<GameConfig>>) -> bool {
+    game_state.game_mode.spawns_critters()
+        && game_config.is_none_or(|config| config.auto_spawn)
+        && !tutorial.is_some_and(|tutorial| tutorial.is_active())
 }
 
 #[derive(Event)]
@@ -80,11 +196,78 @@ pub struct GameProgressEvent {
     pub achievement: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum InteractionType {
     Tap,
     Swipe(Vec2), // direction
     Hold,
+    Pet(PetPhase), // press and release arrive as separate interactions
+    Dwell, // pointer rested on a critter long enough (assistive input); catches like a tap
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PetPhase {
+    Press,
+    Release,
+}
+
+impl InteractionType {
+    /// Parse an interaction name from JS ("tap", "swipe", "hold", "pet_press", "pet_release"); unknown names are rejected
+    pub fn parse(name: &str, direction: Vec2) -> Result<Self, String> {
+        match name {
+            "tap" => Ok(InteractionType::Tap),
+            "swipe" => Ok(InteractionType::Swipe(direction)),
+            "hold" => Ok(InteractionType::Hold),
+            "pet_press" => Ok(InteractionType::Pet(PetPhase::Press)),
+            "pet_release" => Ok(InteractionType::Pet(PetPhase::Release)),
+            "dwell" => Ok(InteractionType::Dwell), // recorded dwell catches replay through the queue
+            other => Err(format!("Unknown interaction type: '{}' (expected tap, swipe, hold, pet_press, pet_release or dwell)", other)),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InteractionType::Tap => "tap",
+            InteractionType::Swipe(_) => "swipe",
+            InteractionType::Hold => "hold",
+            InteractionType::Pet(PetPhase::Press) => "pet_press",
+            InteractionType::Pet(PetPhase::Release) => "pet_release",
+            InteractionType::Dwell => "dwell",
+        }
+    }
+
+    pub fn direction(&self) -> Vec2 {
+        match self {
+            InteractionType::Swipe(direction) => *direction,
+            _ => Vec2::ZERO,
+        }
+    }
+
+    /// Apply input sensitivity: swipes shorter than `min_swipe_px / sensitivity` count as taps,
+    /// longer ones keep their direction with the impulse scaled by sensitivity
+    pub fn with_sensitivity(self, sensitivity: f32, min_swipe_px: f32) -> Self {
+        let InteractionType::Swipe(delta) = self else { return self };
+        let sensitivity = if sensitivity.is_finite() { sensitivity.max(0.1) } else { 1.0 };
+        if delta.length() < min_swipe_px.max(0.0) / sensitivity {
+            InteractionType::Tap
+        } else {
+            InteractionType::Swipe(delta * sensitivity)
+        }
+    }
+}
+
+/// External "well done" trigger: explosion + success sound without a critter
+#[derive(Event)]
+pub struct CelebrateEvent {
+    pub position: Vec2, // world space
+}
+
+/// Play a catalog sound file now, mixed through the Critter bus (catalog_sound_system)
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PlayCatalogSoundEvent {
+    pub path: String,
+    pub label: &'static str,
+    pub volume: f32,
 }
 
 #[derive(Event)]
@@ -92,6 +275,12 @@ pub struct SpawnCritterEvent {
     pub position: Vec2,
 }
 
+/// A critter got away uncaught (escape timeout); it flees off-screen or vanishes per EscapeConfig
+#[derive(Event)]
+pub struct CritterEscapedEvent {
+    pub critter_entity: Entity,
+}
+
 #[derive(Event)]
 pub struct LoadCritterEvent {
     pub critter_id: u32,
@@ -99,3 +288,65 @@ pub struct LoadCritterEvent {
     pub species: String,
     pub id: String, // canonical critter ID used by registry
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct SpawnTicks(u32);
+
+    fn count_spawn_ticks(mut ticks: ResMut<SpawnTicks>) {
+        ticks.0 += 1;
+    }
+
+    #[test]
+    fn test_spawning_enabled_only_while_playing() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<GameState>()
+            .init_resource::<SpawnTicks>()
+            .add_systems(Update, count_spawn_ticks.run_if(spawning_enabled));
+
+        for mode in GameMode::ALL {
+            assert_eq!(GameMode::parse(mode.name()), Ok(mode));
+            app.world_mut().resource_mut::<GameState>().set_mode(mode);
+            app.world_mut().resource_mut::<SpawnTicks>().0 = 0;
+            app.update();
+
+            let spawned = app.world().resource::<SpawnTicks>().0 > 0;
+            assert_eq!(spawned, mode == GameMode::Playing, "spawning in {:?}", mode);
+            assert_eq!(app.world().resource::<GameState>().is_paused, mode == GameMode::Paused);
+        }
+        assert!(GameMode::parse("GAME_OVER").is_ok());
+        assert!(GameMode::parse("lobby").is_err());
+    }
+
+    #[test]
+    fn test_unknown_interaction_type_is_rejected() {
+        assert!(InteractionType::parse("tapp", Vec2::ZERO).is_err());
+        assert!(InteractionType::parse("", Vec2::ZERO).is_err());
+
+        assert_eq!(InteractionType::parse("tap", Vec2::ZERO), Ok(InteractionType::Tap));
+        assert_eq!(InteractionType::parse("hold", Vec2::ZERO), Ok(InteractionType::Hold));
+        assert_eq!(InteractionType::parse(InteractionType::Dwell.name(), Vec2::ZERO), Ok(InteractionType::Dwell));
+        let swipe = InteractionType::parse("swipe", Vec2::X).unwrap();
+        assert_eq!(swipe, InteractionType::Swipe(Vec2::X));
+        assert_eq!(InteractionType::parse(swipe.name(), swipe.direction()), Ok(swipe));
+    }
+
+    #[test]
+    fn test_short_swipes_become_taps_depending_on_sensitivity() {
+        let short = InteractionType::Swipe(Vec2::new(6.0, 0.0));
+        let long = InteractionType::Swipe(Vec2::new(0.0, 40.0));
+
+        assert_eq!(short.with_sensitivity(1.0, 12.0), InteractionType::Tap);
+        assert_eq!(long.with_sensitivity(1.0, 12.0), InteractionType::Swipe(Vec2::new(0.0, 40.0)));
+        // A more sensitive device accepts the short flick as a swipe, with a stronger impulse
+        assert_eq!(short.with_sensitivity(2.5, 12.0), InteractionType::Swipe(Vec2::new(15.0, 0.0)));
+        // A sluggish one needs more travel
+        assert_eq!(long.with_sensitivity(0.25, 12.0), InteractionType::Tap);
+        // Taps and holds are unaffected
+        assert_eq!(InteractionType::Hold.with_sensitivity(0.25, 12.0), InteractionType::Hold);
+    }
+}
@@ -12,11 +12,16 @@ mod components;
 mod effects;
 mod events;
 mod game;
+mod requests;
 mod resources;
+mod save;
 mod systems;
+#[cfg(test)]
+mod test_support;
 
 use audio::{PlatformAudioPlugin, send_audio_response_to_bevy};
 use bluetooth::{
+    send_bluetooth_response_to_bevy,
     BluetoothLEPlugin as BluetoothPlugin,
     BluetoothLERequest as BluetoothRequest,
     BluetoothLEResponse as BluetoothResponse,
@@ -29,14 +34,75 @@ use events::{EventBridgePlugin, BevyToJsEvent, send_js_to_bevy_event};
 use game::{GamePlugin, LoadCritterEvent, SpawnCritterEvent};
 use systems::process_click_on_critters;
 
+// Browser console on the web; native builds (tests) have no console to call into and drop the message
+#[cfg(target_arch = "wasm32")]
+macro_rules! console_log {
+    ($($t:tt)*) => (console::log_1(&format!($($t)*).into()))
+}
+
+#[cfg(target_arch = "wasm32")]
+macro_rules! console_warn {
+    ($($t:tt)*) => (console::warn_1(&format!($($t)*).into()))
+}
+
+#[cfg(target_arch = "wasm32")]
+macro_rules! console_error {
+    ($($t:tt)*) => (console::error_1(&format!($($t)*).into()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_log {
+    ($($t:tt)*) => ({ let _ = format_args!($($t)*); })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_warn {
+    ($($t:tt)*) => ({ let _ = format_args!($($t)*); })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! console_error {
+    ($($t:tt)*) => ({ let _ = format_args!($($t)*); })
+}
+
+// Build identification for diagnostics / bug reports
+pub const BUILD_TIMESTAMP: &str = env!("BUILD_TIMESTAMP");
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildInfo {
+    pub timestamp: &'static str,
+    pub version: &'static str,
+}
+
+pub fn build_info() -> BuildInfo {
+    BuildInfo { timestamp: BUILD_TIMESTAMP, version: CRATE_VERSION }
+}
+
 // Event queues for communication between WASM interface and Bevy
 static LOAD_CRITTER_QUEUE: Mutex<VecDeque<LoadCritterEvent>> = Mutex::new(VecDeque::new());
-static INTERACTION_QUEUE: Mutex<VecDeque<(String, f32, f32, f32, f32)>> = Mutex::new(VecDeque::new());
+static INTERACTION_QUEUE: Mutex<VecDeque<(game::InteractionType, f32, f32)>> = Mutex::new(VecDeque::new());
+// Latest pointer position (screen px) from report_pointer(); only the newest report matters for dwell
+static POINTER_REPORT: Mutex<Option<(f32, f32)>> = Mutex::new(None);
 static AUDIO_EVENT_QUEUE: Mutex<VecDeque<BevyToJsEvent>> = Mutex::new(VecDeque::new());
 static NATIVE_AUDIO_QUEUE: Mutex<VecDeque<audio::AudioRequest>> = Mutex::new(VecDeque::new());
 static BLUETOOTH_REQUEST_QUEUE: Mutex<VecDeque<BluetoothRequest>> = Mutex::new(VecDeque::new());
+// Synthetic responses from push_bluetooth_response(), fed into the response event stream by the Bluetooth plugin
 static BLUETOOTH_RESPONSE_QUEUE: Mutex<VecDeque<BluetoothResponse>> = Mutex::new(VecDeque::new());
 
+// Times a queue lock was recovered after a panic poisoned it; the request being pushed or drained when
+// that panic hit may have been lost, so the count is surfaced via get_queue_diagnostics()
+static QUEUE_LOCK_RECOVERIES: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+/// Lock a bridge queue, recovering (and counting) a poisoned lock instead of dropping the work
+fn lock_queue<T>(queue: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    queue.lock().unwrap_or_else(|poisoned| {
+        QUEUE_LOCK_RECOVERIES.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        queue.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
 // Shared critter list snapshot for UI consumption
 #[derive(Clone, Debug)]
 pub struct CritterSummary {
@@ -56,17 +122,198 @@ pub struct CritterSummary {
 }
 
 static CRITTER_LIST: Mutex<Vec<CritterSummary>> = Mutex::new(Vec::new());
+// Unlocked critter ids, mirrored from CritterRegistry
+static UNLOCKED_CRITTERS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static CATALOG_STATUS: Mutex<Option<systems::RegistryLoadStatus>> = Mutex::new(None);
+static CATALOG_SUMMARY: Mutex<Option<resources::CatalogSummary>> = Mutex::new(None);
 static CRITTERS_READY: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 
+// Live state of the currently spawned critter (None when no critter is active)
+#[derive(Clone, Debug, PartialEq)]
+pub struct CritterStateSnapshot {
+    pub id: String,
+    pub x: f32,
+    pub y: f32,
+    pub happiness: f32,
+    pub energy: f32,
+    pub speed: f32,
+}
+
+static CURRENT_CRITTER_STATE: Mutex<Option<CritterStateSnapshot>> = Mutex::new(None);
+
+// Latest scene mirror for export_state(), and scenes waiting to be rebuilt by import_state()
+static SCENE_SNAPSHOT: Mutex<Option<resources::SceneSnapshot>> = Mutex::new(None);
+static SCENE_IMPORT_QUEUE: Mutex<VecDeque<resources::SceneSnapshot>> = Mutex::new(VecDeque::new());
+
+// One-call support summary for dump_diagnostics(), refreshed every frame by publish_diagnostics
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsSnapshot {
+    pub live_critters: usize,
+    pub mode: &'static str,
+    pub score: u32,
+    pub level: u32,
+    pub lives: Option<u32>,
+    pub audio: AudioDiagnostics,
+    pub bluetooth: BluetoothDiagnostics,
+    pub camera: CameraDiagnostics,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDiagnostics {
+    pub gate_enabled: bool, // catalog sounds unlocked by a gesture
+    pub manager_unlocked: bool,
+    pub muted: bool,
+    pub playing: usize,
+    pub pending: usize,
+    pub error_count: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BluetoothDiagnostics {
+    pub scanning: bool,
+    pub connected_devices: usize,
+    pub discovered_devices: usize,
+    pub virtual_network_enabled: bool,
+    pub error_count: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraDiagnostics {
+    pub total_frames: u64,
+    pub throttled_frames: u64,
+    pub dropped_frames: u64,
+    pub fps: f32,
+    pub last_frame_size: Option<[u32; 2]>,
+}
+
+static DIAGNOSTICS: Mutex<Option<DiagnosticsSnapshot>> = Mutex::new(None);
+
+// Clear color requested by set_background_color(), applied on the next frame
+static BACKGROUND_COLOR_REQUEST: Mutex<Option<Color>> = Mutex::new(None);
+
+// Catalog id and world position of every live critter, for get_nearest_critter()
+static CRITTER_POSITIONS: Mutex<Vec<(String, Vec2)>> = Mutex::new(Vec::new());
+
+// Mirror of the Telemetry resource for get_telemetry(); reset_telemetry() raises the flag
+static TELEMETRY: Mutex<resources::Telemetry> = Mutex::new(resources::Telemetry {
+    critters_spawned: 0,
+    critters_caught: 0,
+    critters_missed: 0,
+    sounds_played: 0,
+    explosions: 0,
+    lives: None,
+});
+// Explosion renderer picked at startup ("sprites" until the render adapter is inspected)
+static PARTICLE_BACKEND: Mutex<effects::ParticleBackend> = Mutex::new(effects::ParticleBackend::Sprites);
+
+pub(crate) fn set_particle_backend(backend: effects::ParticleBackend) {
+    if let Ok(mut g) = PARTICLE_BACKEND.lock() {
+        *g = backend;
+    }
+}
+
+// Catch feedback level last applied, for get_feedback_intensity()
+static FEEDBACK_INTENSITY: Mutex<resources::FeedbackIntensity> = Mutex::new(resources::FeedbackIntensity::High);
+static TELEMETRY_RESET_REQUESTED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+// Tap heatmap, mirrored for get_heatmap()
+static HEATMAP: Mutex<Option<resources::Heatmap>> = Mutex::new(None);
+
+// Current world-space camera view, mirrored for get_camera()
+static CAMERA_VIEW: Mutex<resources::CameraView> = Mutex::new(resources::CameraView { position: Vec2::ZERO, zoom: 1.0 });
+
+// Mirror of CameraPreviewControl so the UI can read back what the engine is showing
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraPreviewSnapshot {
+    pub enabled: bool,
+    pub scale: f32,
+    pub anchor: String,
+    pub mirror_x: bool,
+}
+
+static CAMERA_PREVIEW_STATE: Mutex<Option<CameraPreviewSnapshot>> = Mutex::new(None);
+
+// Known Bluetooth devices (connected + discovered), refreshed whenever the manager changes
+static BLUETOOTH_DEVICES: Mutex<Vec<bluetooth::DeviceInfo>> = Mutex::new(Vec::new());
+
 // Camera preview control system
 #[derive(Debug, Clone)]
 pub enum CameraPreviewRequest {
     Enable { scale: f32, anchor: String },
     Disable,
+    SetFilter { mode: String },
+    SelectSource { source_id: String },
+    SetSafeAreaInsets { top: f32, right: f32, bottom: f32, left: f32 },
+    SetLayers { preview_z: f32, overlay_z: f32 },
+    SetPreviewFps { fps: f32 },
 }
 
 static CAMERA_PREVIEW_QUEUE: Mutex<VecDeque<CameraPreviewRequest>> = Mutex::new(VecDeque::new());
 
+// Runtime gameplay tuning requests, drained from GAME_CONTROL_QUEUE onto the event stream each frame
+#[derive(Event, Debug, Clone)]
+pub enum GameControlRequest {
+    SetTimeScale { scale: f32 },
+    SetMaxFrameDelta { secs: f32 },
+    SetStartingLives { lives: Option<u32> },
+    StartTimedSession { seconds: u32 },
+    SetFeedbackIntensity { level: resources::FeedbackIntensity },
+    SetDwellTime { secs: f32 },
+    SetDespawnStyle { intensity: resources::FeedbackIntensity, style: resources::DespawnStyle },
+    SetMaxExplosions { max: u32 },
+    SetMagnetAssist { strength: f32 },
+    SetSpawnJitter { fraction: f32 },
+    SetSeparation { strength: f32 },
+    SetCuriosity { strength: f32 },
+    SetOffscreenTimeout { secs: f32 },
+    SetCatalogTimeout { secs: f32 },
+    SetInteractionSensitivity { sensitivity: f32, min_swipe_px: Option<f32> },
+    SetInputOffset { dx: f32, dy: f32 },
+    SetPostureSmoothing { window: u32, confidence_threshold: f32 },
+    SetPixelPerfect { critters: Option<bool>, camera_preview: Option<bool> }, // None leaves that target as is
+    SetGameMode { mode: game::GameMode },
+    SetSwipeTrail { enabled: bool },
+    SetHeatmapResolution { cols: u32, rows: u32 },
+    SetTransitionDuration { secs: f32 },
+    SetEscape { timeout_secs: f32, animate: bool, sound_id: Option<String> },
+    SetSpawnSound { enabled: bool, sound_id: Option<String> },
+    UnlockCritter { critter_id: String },
+    SpawnTutorialCritter { critter_id: String },
+    DismissTutorial,
+    SpawnPracticeCritter { critter_id: String },
+    SwitchCritter { critter_id: String, replace_current: bool },
+    CancelInteraction,
+    SetCritterTint { critter_id: String, tint: Option<[f32; 4]> },
+    SkipTransition,
+    SetMinAliveMs { ms: f32 },
+    SetEntrySoundAfterSpawnIn { enabled: bool },
+    SetAutoSpawn { enabled: bool },
+    SpawnCritter,
+    SetCritterCap { max_critters: usize, policy: resources::OverflowPolicy },
+    SetResizeRescale { enabled: bool, keep_velocity: bool },
+    SetExplosionSound { sound_id: Option<String>, critter_id: Option<String> },
+    SetSpawnRotation { enabled: bool, weights: Option<std::collections::HashMap<String, f32>> },
+    SetCamera { x: f32, y: f32, zoom: f32 },
+    AllowMusic { enabled: bool },
+    ConfigureUi { config: resources::UiConfig },
+    StartRecording { seed: u64 },
+    StopRecording,
+    StartPlayback { recording: resources::SessionRecording },
+}
+
+static GAME_CONTROL_QUEUE: Mutex<VecDeque<GameControlRequest>> = Mutex::new(VecDeque::new());
+static CELEBRATION_QUEUE: Mutex<VecDeque<Vec2>> = Mutex::new(VecDeque::new());
+
+// Current game mode, mirrored from GameState for get_game_mode()
+static GAME_MODE: Mutex<game::GameMode> = Mutex::new(game::GameMode::Menu);
+
+// Most recently stopped session recording, serialized as JSON for export
+static LAST_RECORDING: Mutex<Option<String>> = Mutex::new(None);
+
 pub(crate) fn set_available_critters(list: Vec<CritterSummary>) {
     if let Ok(mut g) = CRITTER_LIST.lock() {
         *g = list;
@@ -74,6 +321,116 @@ pub(crate) fn set_available_critters(list: Vec<CritterSummary>) {
     }
 }
 
+pub(crate) fn set_unlocked_critters(ids: Vec<String>) {
+    if let Ok(mut g) = UNLOCKED_CRITTERS.lock() {
+        *g = ids;
+    }
+}
+
+pub(crate) fn set_catalog_status(status: systems::RegistryLoadStatus) {
+    if let Ok(mut g) = CATALOG_STATUS.lock() {
+        *g = Some(status);
+    }
+}
+
+pub(crate) fn catalog_status() -> Option<systems::RegistryLoadStatus> {
+    CATALOG_STATUS.lock().ok().and_then(|g| g.clone())
+}
+
+pub(crate) fn set_catalog_summary(summary: resources::CatalogSummary) {
+    if let Ok(mut g) = CATALOG_SUMMARY.lock() {
+        *g = Some(summary);
+    }
+}
+
+pub(crate) fn catalog_summary() -> Option<resources::CatalogSummary> {
+    CATALOG_SUMMARY.lock().ok().and_then(|g| g.clone())
+}
+
+pub(crate) fn unlocked_critters() -> Vec<String> {
+    UNLOCKED_CRITTERS.lock().map(|g| g.clone()).unwrap_or_default()
+}
+
+pub(crate) fn is_critter_unlocked(critter_id: &str) -> bool {
+    UNLOCKED_CRITTERS.lock().is_ok_and(|g| g.iter().any(|id| id == critter_id))
+}
+
+pub(crate) fn set_current_critter_state(state: Option<CritterStateSnapshot>) {
+    if let Ok(mut g) = CURRENT_CRITTER_STATE.lock() {
+        *g = state;
+    }
+}
+
+pub(crate) fn current_critter_state() -> Option<CritterStateSnapshot> {
+    CURRENT_CRITTER_STATE.lock().ok().and_then(|g| g.clone())
+}
+
+pub(crate) fn set_scene_snapshot(snapshot: resources::SceneSnapshot) {
+    if let Ok(mut g) = SCENE_SNAPSHOT.lock() {
+        *g = Some(snapshot);
+    }
+}
+
+pub(crate) fn scene_snapshot() -> Option<resources::SceneSnapshot> {
+    SCENE_SNAPSHOT.lock().ok().and_then(|g| g.clone())
+}
+
+pub(crate) fn queue_scene_import(scene: resources::SceneSnapshot) {
+    lock_queue(&SCENE_IMPORT_QUEUE).push_back(scene);
+}
+
+/// Validated sRGB clear color; every component must be within [0, 1]
+pub(crate) fn background_color(r: f32, g: f32, b: f32) -> Result<Color, String> {
+    for (name, value) in [("r", r), ("g", g), ("b", b)] {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(format!("Background color component {} must be within [0, 1], got {}", name, value));
+        }
+    }
+    Ok(Color::srgb(r, g, b))
+}
+
+pub(crate) fn diagnostics() -> Option<DiagnosticsSnapshot> {
+    DIAGNOSTICS.lock().ok().and_then(|g| g.clone())
+}
+
+pub(crate) fn queue_background_color(color: Color) {
+    if let Ok(mut g) = BACKGROUND_COLOR_REQUEST.lock() {
+        *g = Some(color);
+    }
+}
+
+/// Most recently imported scene; earlier ones queued in the same frame would be replaced anyway
+pub(crate) fn take_scene_import() -> Option<resources::SceneSnapshot> {
+    lock_queue(&SCENE_IMPORT_QUEUE).drain(..).last()
+}
+
+pub(crate) fn set_critter_positions(positions: Vec<(String, Vec2)>) {
+    if let Ok(mut g) = CRITTER_POSITIONS.lock() {
+        *g = positions;
+    }
+}
+
+pub(crate) fn nearest_critter_position(point: Vec2) -> Option<(String, Vec2)> {
+    let positions = CRITTER_POSITIONS.lock().ok()?;
+    systems::nearest_critter(point, positions.iter().map(|(id, pos)| (id, *pos)))
+        .map(|(id, pos, _)| (id.clone(), pos))
+}
+
+/// Mirror a mode the engine switched to on its own (e.g. game over) for get_game_mode()
+pub(crate) fn publish_game_mode(mode: game::GameMode) {
+    if let Ok(mut current) = GAME_MODE.lock() {
+        *current = mode;
+    }
+}
+
+pub(crate) fn queue_game_mode(mode: game::GameMode) {
+    lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetGameMode { mode });
+}
+
+pub(crate) fn queue_interaction(interaction: game::InteractionType, x: f32, y: f32) {
+    lock_queue(&INTERACTION_QUEUE).push_back((interaction, x, y));
+}
+
 // Enable better panic messages in development
 #[cfg(feature = "console_error_panic_hook")]
 pub fn set_panic_hook() {
@@ -86,8 +443,7 @@ pub fn main() {
     #[cfg(feature = "console_error_panic_hook")]
     set_panic_hook();
 
-    let build_timestamp = env!("BUILD_TIMESTAMP");
-    console::log_1(&format!("🐕 App4.Dog Game Engine Starting... [v2024-EXPLOSION-FIX] Built: {}", build_timestamp).into());
+    console::log_1(&format!("🐕 App4.Dog Game Engine Starting... [v2024-EXPLOSION-FIX] v{} Built: {}", CRATE_VERSION, BUILD_TIMESTAMP).into());
     
     App::new()
         .add_plugins(WebAssetPlugin::default())
@@ -113,14 +469,28 @@ pub fn main() {
         .add_plugins(BluetoothPlugin)
         .add_plugins(CameraPlugin)
         .add_plugins(effects::ExplosionEffectsPlugin)
+        .add_plugins(effects::SwipeTrailPlugin)
+        .add_event::<GameControlRequest>()
         .add_systems(Update, (
             process_load_critter_queue,
             process_interaction_queue,
+            process_pointer_report.before(systems::dwell_system),
+            apply_background_color,
             process_audio_event_queue,
             process_native_audio_queue,
             process_bluetooth_request_queue,
             process_bluetooth_response_queue,
             process_camera_preview_queue,
+            process_game_control_queue,
+            (apply_critter_controls, apply_feedback_controls, apply_view_controls, apply_session_controls)
+                .after(process_game_control_queue),
+            systems::session_clock_system.after(apply_session_controls),
+            process_celebration_queue,
+            publish_bluetooth_devices,
+            publish_camera_preview_state.after(process_camera_preview_queue),
+            publish_telemetry,
+            publish_heatmap,
+            publish_diagnostics,
         ))
         .run();
 }
@@ -142,13 +512,193 @@ impl GameEngine {
     #[wasm_bindgen]
     pub fn start_game(&self) {
         console::log_1(&"🚀 Game starting...".into());
-        // Future: trigger game start event
+        queue_game_mode(game::GameMode::Playing);
     }
 
     #[wasm_bindgen]
     pub fn pause_game(&self) {
         console::log_1(&"⏸️ Game paused".into());
-        // Future: pause game systems
+        queue_game_mode(game::GameMode::Paused);
+    }
+
+    /// Pan/zoom the game camera: (x, y) is the world point at the screen center, zoom > 1 magnifies (clamped to 0.25..4)
+    #[wasm_bindgen]
+    pub fn set_camera(&self, x: f32, y: f32, zoom: f32) {
+        console::log_1(&format!("🎥 Setting camera: ({}, {}) zoom {}", x, y, zoom).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCamera { x, y, zoom });
+    }
+
+    /// Current camera view as {x, y, zoom}
+    #[wasm_bindgen]
+    pub fn get_camera(&self) -> JsValue {
+        let view = CAMERA_VIEW.lock().map(|v| *v).unwrap_or_default();
+        let o = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&o, &"x".into(), &view.position.x.into());
+        let _ = js_sys::Reflect::set(&o, &"y".into(), &view.position.y.into());
+        let _ = js_sys::Reflect::set(&o, &"zoom".into(), &view.zoom.into());
+        o.into()
+    }
+
+    /// Show (or hide) the fading trail drawn along swipes
+    #[wasm_bindgen]
+    pub fn set_swipe_trail(&self, enabled: bool) {
+        console::log_1(&format!("✨ Swipe trail: {}", if enabled { "on" } else { "off" }).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetSwipeTrail { enabled });
+    }
+
+    /// Give the catalog load this many seconds before it fails with an EngineError "catalog_timeout"
+    /// (answer with reload_catalog() to retry); 0.0 waits forever
+    #[wasm_bindgen]
+    pub fn set_catalog_timeout(&self, secs: f32) {
+        console::log_1(&format!("⏰ Setting catalog load timeout: {}s", secs).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCatalogTimeout { secs });
+    }
+
+    /// Re-fetch the critter catalog and RON files (content iteration, no page reload needed)
+    #[wasm_bindgen]
+    pub fn reload_catalog(&self) {
+        console::log_1(&"🔄 Catalog reload requested".into());
+        systems::request_catalog_reload();
+    }
+
+    /// Grid size of the tap heatmap (see get_heatmap()); each side 1-64. Changing it starts a fresh heatmap
+    #[wasm_bindgen]
+    pub fn set_heatmap_resolution(&self, cols: u32, rows: u32) -> Result<(), JsValue> {
+        if !(1..=resources::Heatmap::MAX_DIM).contains(&cols) || !(1..=resources::Heatmap::MAX_DIM).contains(&rows) {
+            return Err(JsValue::from_str(&format!("Heatmap resolution must be 1-{} per side", resources::Heatmap::MAX_DIM)));
+        }
+        console::log_1(&format!("🗺️ Setting heatmap resolution: {}x{}", cols, rows).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetHeatmapResolution { cols, rows });
+        Ok(())
+    }
+
+    /// Current game mode name ("menu", "playing", "paused", "game_over", "demo", "training")
+    #[wasm_bindgen]
+    pub fn get_game_mode(&self) -> String {
+        GAME_MODE.lock().map(|mode| mode.name()).unwrap_or("menu").to_string()
+    }
+
+    /// JSON array of every game mode name accepted by set_game_mode()
+    #[wasm_bindgen]
+    pub fn get_game_modes(&self) -> String {
+        serde_json::to_string(&game::GameMode::ALL.map(|mode| mode.name())).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Switch game mode; critters only auto-spawn while "playing"
+    #[wasm_bindgen]
+    pub fn set_game_mode(&self, mode: &str) -> Result<(), JsValue> {
+        let mode = game::GameMode::parse(mode).map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🎮 Setting game mode: {}", mode.name()).into());
+        queue_game_mode(mode);
+        Ok(())
+    }
+
+    /// Tutorial mode: replace the scene with one stationary `id` critter at screen center and pause auto-spawn
+    /// until it's caught or dismiss_tutorial() is called (e.g. for a "tap the bird" overlay)
+    #[wasm_bindgen]
+    pub fn spawn_tutorial_critter(&self, id: &str) {
+        console::log_1(&format!("🎓 Spawning tutorial critter: {}", id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SpawnTutorialCritter { critter_id: id.to_string() });
+    }
+
+    /// End the tutorial: the tutorial critter starts moving and auto-spawn resumes
+    #[wasm_bindgen]
+    pub fn dismiss_tutorial(&self) {
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::DismissTutorial);
+    }
+
+    /// Practice/calibration: replace the scene with an `id` critter that respawns in place every time it's caught
+    #[wasm_bindgen]
+    pub fn spawn_practice_critter(&self, id: &str) {
+        console::log_1(&format!("🎯 Spawning practice critter: {}", id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SpawnPracticeCritter { critter_id: id.to_string() });
+    }
+
+    /// Change the selected critter mid-session; by default the one on screen is swapped out right away
+    /// (pass `replace_current: false` to only affect the next spawn, like load_critter_by_id)
+    #[wasm_bindgen]
+    pub fn switch_critter(&self, id: &str, replace_current: Option<bool>) {
+        let replace_current = replace_current.unwrap_or(true);
+        console::log_1(&format!("🔀 Switching critter to {} (replace current: {})", id, replace_current).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SwitchCritter { critter_id: id.to_string(), replace_current });
+    }
+
+    /// Tint a critter's sprite (RGBA, each 0-1; alpha defaults to 1). Overrides the catalog's `tint:` and
+    /// applies from the next spawn; critters already on screen keep their color
+    #[wasm_bindgen]
+    pub fn set_critter_tint(&self, critter_id: &str, r: f32, g: f32, b: f32, a: Option<f32>) -> Result<(), JsValue> {
+        let tint = [r, g, b, a.unwrap_or(1.0)];
+        if tint.iter().any(|c| !c.is_finite() || !(0.0..=1.0).contains(c)) {
+            return Err(JsValue::from_str("Tint channels must be between 0 and 1"));
+        }
+        console::log_1(&format!("🎨 Tinting critter {}: {:?}", critter_id, tint).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCritterTint { critter_id: critter_id.to_string(), tint: Some(tint) });
+        Ok(())
+    }
+
+    /// Drop a critter's tint so it spawns with its plain sprite colors
+    #[wasm_bindgen]
+    pub fn clear_critter_tint(&self, critter_id: &str) {
+        console::log_1(&format!("🎨 Clearing tint for critter {}", critter_id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCritterTint { critter_id: critter_id.to_string(), tint: None });
+    }
+
+    /// Unlock a catalog critter for the player (ids missing from the catalog are ignored)
+    #[wasm_bindgen]
+    pub fn unlock_critter(&self, critter_id: &str) {
+        console::log_1(&format!("🔓 Unlocking critter: {}", critter_id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::UnlockCritter { critter_id: critter_id.to_string() });
+    }
+
+    /// Play a registered UI blip on every spawn, the same for all critters (off by default);
+    /// sound_id keeps the current blip when omitted
+    #[wasm_bindgen]
+    pub fn set_spawn_sound(&self, enabled: bool, sound_id: Option<String>) {
+        console::log_1(&format!("🔔 Spawn sound {} ({:?})", if enabled { "on" } else { "off" }, sound_id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetSpawnSound { enabled, sound_id });
+    }
+
+    /// Let critters escape after timeout_secs uncaught (0 = never); animate runs them off the nearest
+    /// edge instead of removing them on the spot, with an optional registered escape sound
+    #[wasm_bindgen]
+    pub fn set_escape(&self, timeout_secs: f32, animate: bool, sound_id: Option<String>) {
+        console::log_1(&format!("🏃 Escape after {}s ({}, sound {:?})", timeout_secs,
+            if animate { "flee off-screen" } else { "vanish" }, sound_id).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetEscape { timeout_secs, animate, sound_id });
+    }
+
+    /// Length of the fade played on mode changes in seconds (0 switches instantly; default 0.4)
+    #[wasm_bindgen]
+    pub fn set_transition_duration(&self, secs: f32) {
+        console::log_1(&format!("🌒 Setting mode transition: {}s", secs).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetTransitionDuration { secs });
+    }
+
+    /// Abandon any pet or dwell in progress without scoring it, e.g. when the pointer leaves the canvas mid-gesture
+    #[wasm_bindgen]
+    pub fn cancel_interaction(&self) {
+        console::log_1(&"✋ Cancelling in-progress interaction".into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::CancelInteraction);
+    }
+
+    /// Clear a running mode transition so the new mode shows (and takes input) right away
+    #[wasm_bindgen]
+    pub fn skip_transition(&self) {
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SkipTransition);
     }
 
     #[wasm_bindgen]
@@ -158,14 +708,16 @@ impl GameEngine {
     }
 
     #[wasm_bindgen]
-    pub fn handle_interaction(&self, interaction_type: &str, x: f32, y: f32, dir_x: f32, dir_y: f32) {
+    pub fn handle_interaction(&self, interaction_type: &str, x: f32, y: f32, dir_x: f32, dir_y: f32) -> Result<(), JsValue> {
         console::log_1(&format!("🐾 Pet interaction received: {} at ({}, {}) with direction ({}, {})", 
             interaction_type, x, y, dir_x, dir_y).into());
         
+        let interaction = game::InteractionType::parse(interaction_type, Vec2::new(dir_x, dir_y))
+            .map_err(|e| JsValue::from_str(&e))?;
+        
         // Queue the interaction for processing by Bevy
-        if let Ok(mut queue) = INTERACTION_QUEUE.lock() {
-            queue.push_back((interaction_type.to_string(), x, y, dir_x, dir_y));
-        }
+        queue_interaction(interaction, x, y);
+        Ok(())
     }
 
     #[wasm_bindgen]
@@ -174,30 +726,53 @@ impl GameEngine {
             critter_id, name, species).into());
         
         // Queue the critter load event for processing by Bevy
-        if let Ok(mut queue) = LOAD_CRITTER_QUEUE.lock() {
-            queue.push_back(LoadCritterEvent {
-                critter_id,
-                name: name.to_string(),
-                species: species.to_string(),
-                id: name.to_string(), // back-compat bridge (deprecated)
-            });
-        }
+        lock_queue(&LOAD_CRITTER_QUEUE).push_back(LoadCritterEvent {
+            critter_id,
+            name: name.to_string(),
+            species: species.to_string(),
+            id: name.to_string(), // back-compat bridge (deprecated)
+        });
     }
 
     /// Preferred API: load a critter by canonical ID string
     #[wasm_bindgen]
     pub fn load_critter_by_id(&self, id: &str) {
         console::log_1(&format!("🐶 Loading critter by id: {}", id).into());
-        if let Ok(mut queue) = LOAD_CRITTER_QUEUE.lock() {
-            queue.push_back(LoadCritterEvent {
-                critter_id: 0,
-                name: String::new(),
-                species: String::new(),
-                id: id.to_string(),
-            });
+        lock_queue(&LOAD_CRITTER_QUEUE).push_back(LoadCritterEvent {
+            critter_id: 0,
+            name: String::new(),
+            species: String::new(),
+            id: id.to_string(),
+        });
+    }
+
+    /// Scene background (clear color) as sRGB components in [0, 1], e.g. to match the app's brand or for high contrast
+    #[wasm_bindgen]
+    pub fn set_background_color(&self, r: f32, g: f32, b: f32) -> Result<(), JsValue> {
+        let color = background_color(r, g, b).map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🎨 Setting background color: ({}, {}, {})", r, g, b).into());
+
+        queue_background_color(color);
+        Ok(())
+    }
+
+    /// Continuous pointer position (screen px) for dwell catching with gaze trackers or slow pointers;
+    /// resting on a critter for the dwell time (set_dwell_time) catches it without a click
+    #[wasm_bindgen]
+    pub fn report_pointer(&self, x: f32, y: f32) {
+        if let Ok(mut pointer) = POINTER_REPORT.lock() {
+            *pointer = Some((x, y));
         }
     }
 
+    /// Seconds the pointer must rest on a critter to catch it (0 turns dwell catching off)
+    #[wasm_bindgen]
+    pub fn set_dwell_time(&self, secs: f32) {
+        console::log_1(&format!("👁️ Setting dwell time: {}s", secs).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetDwellTime { secs });
+    }
+
     #[wasm_bindgen]
     pub fn get_critter_info(&self) -> js_sys::Object {
         // Return current critter information as JS object
@@ -224,13 +799,11 @@ impl GameEngine {
         console::log_1(&format!("🎵 Requesting audio via bridge: {} (request_id: {})", sound_id, request_id).into());
         
         // We need to trigger this from within a Bevy system, so we'll use the same pattern as other events
-        if let Ok(mut queue) = AUDIO_EVENT_QUEUE.lock() {
-            queue.push_back(BevyToJsEvent::PlayAudio {
-                request_id: request_id.clone(),
-                sound_id: sound_id.to_string(),
-                volume,
-            });
-        }
+        lock_queue(&AUDIO_EVENT_QUEUE).push_back(BevyToJsEvent::PlayAudio {
+            request_id: request_id.clone(),
+            sound_id: sound_id.to_string(),
+            volume,
+        });
         
         request_id
     }
@@ -242,19 +815,88 @@ impl GameEngine {
         console::log_1(&format!("🎵 Playing audio via AudioPlugin: {} (request_id: {})", sound_id, request_id).into());
         
         // Queue audio request for the AudioPlugin to process
-        if let Ok(mut queue) = NATIVE_AUDIO_QUEUE.lock() {
-            queue.push_back(audio::AudioRequest::Play {
-                request_id: request_id.clone(),
-                sound_id: sound_id.to_string(),
-                context: audio::AudioContext::Test,
-                volume: volume.unwrap_or(0.8),
-                loop_audio: false,
-            });
-        }
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::Play {
+            request_id: request_id.clone(),
+            sound_id: sound_id.to_string(),
+            context: audio::AudioContext::Test,
+            volume: volume.unwrap_or(0.8),
+            loop_audio: false,
+            source_url: None,
+        });
         
         request_id
     }
     
+    /// Set the volume bus for an audio context ("enter", "exit", "ui", "critter", "ambient", "test")
+    #[wasm_bindgen]
+    pub fn set_context_volume(&self, context: &str, volume: f32) -> Result<(), JsValue> {
+        let context = audio::AudioContext::from_name(context)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown audio context: {}", context)))?;
+        console::log_1(&format!("🎚️ Setting {:?} volume: {}", context, volume).into());
+        
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::SetContextVolume {
+            request_id: audio::AudioManager::generate_request_id(),
+            context,
+            volume,
+        });
+        
+        Ok(())
+    }
+    
+    /// Mute or unmute all game audio; volume settings are kept, looping tracks pause and pick up again on unmute
+    #[wasm_bindgen]
+    pub fn set_muted(&self, muted: bool) {
+        console::log_1(&format!("{} Setting mute: {}", if muted { "🔇" } else { "🔊" }, muted).into());
+
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::SetMuted {
+            request_id: audio::AudioManager::generate_request_id(),
+            muted,
+        });
+    }
+    
+    /// Cap how many one-shot sounds play at once (the oldest is stopped to make room; looping tracks are exempt)
+    #[wasm_bindgen]
+    pub fn set_max_audio_voices(&self, max_voices: u32) -> Result<(), JsValue> {
+        if max_voices == 0 {
+            return Err(JsValue::from_str("max_voices must be at least 1"));
+        }
+        console::log_1(&format!("🎚️ Setting max audio voices: {}", max_voices).into());
+        
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::SetMaxVoices {
+            request_id: audio::AudioManager::generate_request_id(),
+            max_voices: max_voices as usize,
+        });
+        
+        Ok(())
+    }
+    
+    /// Flush pending audio requests that never received a response from JS
+    #[wasm_bindgen]
+    pub fn flush_stale_audio_requests(&self) {
+        console::log_1(&"🧹 Flushing stale audio requests".into());
+        
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::FlushStaleRequests {
+            request_id: audio::AudioManager::generate_request_id(),
+        });
+    }
+    
+    /// Play the registered sound for an audio context ("enter", "exit", "ui", ...), e.g. as the dog moves between zones
+    #[wasm_bindgen]
+    pub fn play_context_sound(&self, context: &str) -> Result<String, JsValue> {
+        let context = audio::AudioContext::from_name(context)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown audio context: {}", context)))?;
+        let request_id = audio::AudioManager::generate_request_id();
+        console::log_1(&format!("🎵 Playing {:?} context sound (request_id: {})", context, request_id).into());
+        
+        lock_queue(&NATIVE_AUDIO_QUEUE).push_back(audio::AudioRequest::PlayContext {
+            request_id: request_id.clone(),
+            context,
+            volume: None,
+        });
+        
+        Ok(request_id)
+    }
+    
     /// Play enter area sound
     #[wasm_bindgen]
     pub fn play_enter_sound(&self) -> String {
@@ -275,12 +917,10 @@ impl GameEngine {
         let request_id = format!("bt-scan-{}", js_sys::Date::now() as u64);
         console::log_1(&format!("🔵 Starting Bluetooth scan (request_id: {})", request_id).into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::StartScan {
-                duration_ms,
-                device_filter: None, // Can be extended later
-            });
-        }
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::StartScan {
+            duration_ms,
+            device_filter: None, // Can be extended later
+        });
         
         request_id
     }
@@ -290,9 +930,7 @@ impl GameEngine {
     pub fn stop_bluetooth_scan(&self) {
         console::log_1(&"🔵 Stopping Bluetooth scan".into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::StopScan);
-        }
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::StopScan);
     }
 
     /// Connect to a Bluetooth device
@@ -301,11 +939,9 @@ impl GameEngine {
         let request_id = format!("bt-connect-{}", js_sys::Date::now() as u64);
         console::log_1(&format!("🔵 Connecting to device: {} (request_id: {})", device_id, request_id).into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::Connect {
-                device_id: DeviceId(device_id.to_string()),
-            });
-        }
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::Connect {
+            device_id: DeviceId(device_id.to_string()),
+        });
         
         request_id
     }
@@ -315,11 +951,9 @@ impl GameEngine {
     pub fn disconnect_bluetooth_device(&self, device_id: &str) {
         console::log_1(&format!("🔵 Disconnecting from device: {}", device_id).into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::Disconnect {
-                device_id: DeviceId(device_id.to_string()),
-            });
-        }
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::Disconnect {
+            device_id: DeviceId(device_id.to_string()),
+        });
     }
 
     /// Enable virtual Bluetooth network for testing
@@ -327,26 +961,50 @@ impl GameEngine {
     pub fn enable_virtual_bluetooth(&self) {
         console::log_1(&"🔵 Enabling virtual Bluetooth network".into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::EnableVirtualNetwork);
-            
-            // Register test devices
-            for virtual_device in create_test_virtual_devices() {
-                queue.push_back(BluetoothRequest::RegisterVirtualDevice {
-                    device: virtual_device,
-                });
-            }
+        let mut queue = lock_queue(&BLUETOOTH_REQUEST_QUEUE);
+        queue.push_back(BluetoothRequest::EnableVirtualNetwork);
+        
+        // Register test devices
+        for virtual_device in create_test_virtual_devices() {
+            queue.push_back(BluetoothRequest::RegisterVirtualDevice {
+                device: virtual_device,
+            });
         }
     }
 
+    /// Play a built-in scripted Bluetooth scenario (e.g. "collar-pairing") against the virtual network:
+    /// devices are registered, then scan/connect/command responses arrive on the usual timeline
+    #[wasm_bindgen]
+    pub fn run_virtual_scenario(&self, name: &str) -> Result<(), JsValue> {
+        if bluetooth::builtin_scenario(name).is_none() {
+            return Err(JsValue::from_str(&format!(
+                "Unknown virtual scenario '{}' (expected one of: {})",
+                name,
+                bluetooth::BUILTIN_SCENARIOS.join(", ")
+            )));
+        }
+        console::log_1(&format!("🔵 Running virtual Bluetooth scenario: {}", name).into());
+        
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::RunVirtualScenario {
+            name: name.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Log every Bluetooth request/response (off by default; errors are always logged)
+    #[wasm_bindgen]
+    pub fn set_bluetooth_verbose(&self, enabled: bool) {
+        console::log_1(&format!("🔵 Verbose Bluetooth logging {}", if enabled { "on" } else { "off" }).into());
+        
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::SetVerboseLogging { enabled });
+    }
+
     /// Disable virtual Bluetooth network
     #[wasm_bindgen]
     pub fn disable_virtual_bluetooth(&self) {
         console::log_1(&"🔵 Disabling virtual Bluetooth network".into());
         
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            queue.push_back(BluetoothRequest::DisableVirtualNetwork);
-        }
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::DisableVirtualNetwork);
     }
 
     /// Send a command to a Bluetooth device (Zephyr protocol)
@@ -358,22 +1016,20 @@ impl GameEngine {
         
         // Parse command JSON - for now use a simple command
         // In practice this would deserialize from JSON to ZephyrCommand
-        if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-            // Simplified command parsing for demo
-            let command = if command_json.contains("battery") {
-                bluetooth::ZephyrCommand::GetBatteryLevel
-            } else if command_json.contains("info") {
-                bluetooth::ZephyrCommand::GetDeviceInfo
-            } else {
-                bluetooth::ZephyrCommand::GetDeviceInfo // Default
-            };
-            
-            queue.push_back(BluetoothRequest::SendCommand {
-                device_id: DeviceId(device_id.to_string()),
-                command,
-                timeout_ms: Some(5000),
-            });
-        }
+        // Simplified command parsing for demo
+        let command = if command_json.contains("battery") {
+            bluetooth::ZephyrCommand::GetBatteryLevel
+        } else if command_json.contains("info") {
+            bluetooth::ZephyrCommand::GetDeviceInfo
+        } else {
+            bluetooth::ZephyrCommand::GetDeviceInfo // Default
+        };
+        
+        lock_queue(&BLUETOOTH_REQUEST_QUEUE).push_back(BluetoothRequest::SendCommand {
+            device_id: DeviceId(device_id.to_string()),
+            command,
+            timeout_ms: Some(5000),
+        });
         
         request_id
     }
@@ -415,12 +1071,10 @@ impl GameEngine {
         let request_id = format!("preview-{}", js_sys::Date::now() as u64);
         console::log_1(&format!("📹 Enabling camera preview: scale={}, anchor={}", scale, anchor).into());
         
-        if let Ok(mut queue) = CAMERA_PREVIEW_QUEUE.lock() {
-            queue.push_back(CameraPreviewRequest::Enable {
-                scale,
-                anchor: anchor.to_string(),
-            });
-        }
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::Enable {
+            scale,
+            anchor: anchor.to_string(),
+        });
         
         request_id
     }
@@ -431,12 +1085,354 @@ impl GameEngine {
         let request_id = format!("preview-off-{}", js_sys::Date::now() as u64);
         console::log_1(&"📹 Disabling camera preview".into());
         
-        if let Ok(mut queue) = CAMERA_PREVIEW_QUEUE.lock() {
-            queue.push_back(CameraPreviewRequest::Disable);
-        }
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::Disable);
         
         request_id
     }
+
+    /// Draw order of the preview and FPS overlay; the preview is kept above critters (z=100)
+    #[wasm_bindgen]
+    pub fn set_camera_preview_layers(&self, preview_z: f32, overlay_z: f32) {
+        console::log_1(&format!("📹 Setting camera preview layers: preview_z={}, overlay_z={}", preview_z, overlay_z).into());
+
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::SetLayers { preview_z, overlay_z });
+    }
+
+    /// Cap how often the preview thumbnail is redrawn (0 = every processed frame); recognition keeps its own rate
+    #[wasm_bindgen]
+    pub fn set_camera_preview_fps(&self, fps: f32) -> Result<(), JsValue> {
+        if !fps.is_finite() || fps < 0.0 {
+            return Err(JsValue::from_str(&format!("Invalid preview fps {}: expected 0 or more", fps)));
+        }
+        console::log_1(&format!("📹 Setting camera preview fps: {}", fps).into());
+
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::SetPreviewFps { fps });
+        Ok(())
+    }
+
+    /// Choose which camera source the preview shows (e.g. "front", "rear", "default")
+    #[wasm_bindgen]
+    pub fn set_preview_source(&self, source_id: &str) {
+        console::log_1(&format!("📹 Setting camera preview source: {}", source_id).into());
+
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::SelectSource {
+            source_id: source_id.to_string(),
+        });
+    }
+
+    /// Pass CSS env(safe-area-inset-*) values so the preview avoids notches and rounded corners
+    #[wasm_bindgen]
+    pub fn set_safe_area_insets(&self, top: f32, right: f32, bottom: f32, left: f32) {
+        console::log_1(&format!("📹 Setting safe area insets: top={} right={} bottom={} left={}", top, right, bottom, left).into());
+
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::SetSafeAreaInsets { top, right, bottom, left });
+    }
+
+    /// Scale gameplay time (0.5 = slow-motion, 2.0 = fast-forward, 0.0 = soft pause)
+    #[wasm_bindgen]
+    pub fn set_time_scale(&self, scale: f32) {
+        console::log_1(&format!("⏱️ Setting time scale: {}", scale).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetTimeScale { scale });
+    }
+
+    /// Longest step (seconds) the game clock takes in one frame; after a stall critters move at most this much
+    #[wasm_bindgen]
+    pub fn set_max_frame_delta(&self, secs: f32) {
+        console::log_1(&format!("⏱️ Setting max frame delta: {}s", secs).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetMaxFrameDelta { secs });
+    }
+
+    /// Lives per game: each escaped critter costs one and losing the last means game over;
+    /// omit (or pass undefined) for unlimited lives. Also refills the current game's lives
+    #[wasm_bindgen]
+    pub fn set_starting_lives(&self, lives: Option<u32>) -> Result<(), JsValue> {
+        if lives == Some(0) {
+            return Err(JsValue::from_str("Starting lives must be at least 1 (omit for unlimited)"));
+        }
+        console::log_1(&format!("❤️ Setting starting lives: {}", lives.map_or("unlimited".to_string(), |n| n.to_string())).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetStartingLives { lives });
+        Ok(())
+    }
+
+    /// Arcade mode: start a fresh game that ends (game over, final score kept) when `seconds` run out.
+    /// The on-screen timer pauses with the game
+    #[wasm_bindgen]
+    pub fn start_timed_session(&self, seconds: u32) -> Result<(), JsValue> {
+        if seconds == 0 {
+            return Err(JsValue::from_str("Timed session must last at least 1 second"));
+        }
+        console::log_1(&format!("⏳ Starting timed session: {}s", seconds).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::StartTimedSession { seconds });
+        Ok(())
+    }
+
+    /// Catch feedback size for sensory-sensitive dogs: "low" (small, quiet), "medium" or "high" (full effect)
+    #[wasm_bindgen]
+    pub fn set_feedback_intensity(&self, level: &str) -> Result<(), JsValue> {
+        let level = resources::FeedbackIntensity::parse(level).map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🎚️ Setting feedback intensity: {}", level.name()).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetFeedbackIntensity { level });
+        Ok(())
+    }
+
+    /// How catches leave the board at a feedback intensity: "explode" or "fade_out" (calm: shrink and fade,
+    /// no particles). Defaults: low fades, medium and high explode
+    #[wasm_bindgen]
+    pub fn set_despawn_style(&self, intensity: &str, style: &str) -> Result<(), JsValue> {
+        let intensity = resources::FeedbackIntensity::parse(intensity).map_err(|e| JsValue::from_str(&e))?;
+        let style = resources::DespawnStyle::parse(style).map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🎚️ Catches at {} intensity now {:?}", intensity.name(), style).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetDespawnStyle { intensity, style });
+        Ok(())
+    }
+
+    /// Cap how many explosions may play at once (protects framerate during combo bursts)
+    #[wasm_bindgen]
+    pub fn set_max_concurrent_explosions(&self, max: u32) {
+        console::log_1(&format!("🎆 Setting max concurrent explosions: {}", max).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetMaxExplosions { max });
+    }
+
+    /// Enable the magnet assist (critters drift toward the last tap); 0.0 disables it
+    #[wasm_bindgen]
+    pub fn set_magnet_assist(&self, strength: f32) {
+        console::log_1(&format!("🧲 Setting magnet assist strength: {}", strength).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetMagnetAssist { strength });
+    }
+
+    /// Randomize auto-spawn timing by ± this fraction of the interval (0.0 disables jitter)
+    #[wasm_bindgen]
+    pub fn set_spawn_jitter(&self, fraction: f32) {
+        console::log_1(&format!("🎲 Setting spawn jitter: ±{}", fraction).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetSpawnJitter { fraction });
+    }
+
+    /// Celebrate at a world position (explosion + success sound), e.g. when hardware detects a good behavior
+    #[wasm_bindgen]
+    pub fn trigger_celebration(&self, x: f32, y: f32) {
+        console::log_1(&format!("🎉 Celebration triggered at ({}, {})", x, y).into());
+
+        lock_queue(&CELEBRATION_QUEUE).push_back(Vec2::new(x, y));
+    }
+
+    /// Pick each spawned critter at random from the catalog, weighted by e.g. {"chirpy_bird": 3, "rare_bunny": 0.5}
+    /// (unlisted critters weigh 1); weights_json keeps the current weights when omitted
+    #[wasm_bindgen]
+    pub fn set_spawn_rotation(&self, enabled: bool, weights_json: Option<String>) -> Result<(), JsValue> {
+        let weights = weights_json
+            .map(|json| serde_json::from_str::<std::collections::HashMap<String, f32>>(&json))
+            .transpose()
+            .map_err(|e| JsValue::from_str(&format!("Invalid spawn weights: {}", e)))?;
+        if let Some((id, weight)) = weights.iter().flatten().find(|(_, w)| !w.is_finite() || **w < 0.0) {
+            return Err(JsValue::from_str(&format!("Spawn weight for {} must be a non-negative number, got {}", id, weight)));
+        }
+        console::log_1(&format!("🎰 Spawn rotation {} (weights: {:?})", if enabled { "on" } else { "off" }, weights).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetSpawnRotation { enabled, weights });
+        Ok(())
+    }
+
+    /// Registered sound played under the success sound when a critter explodes; critter_id scopes it to one
+    /// catalog critter, and a missing sound_id clears it (default: no explosion sound)
+    #[wasm_bindgen]
+    pub fn set_explosion_sound(&self, sound_id: Option<String>, critter_id: Option<String>) {
+        console::log_1(&format!("💥 Setting explosion sound {:?} for {}", sound_id,
+            critter_id.as_deref().unwrap_or("all critters")).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetExplosionSound { sound_id, critter_id });
+    }
+
+    /// Move live critters proportionally when the canvas resizes; keep_velocity leaves their velocities unscaled
+    #[wasm_bindgen]
+    pub fn set_resize_rescale(&self, enabled: bool, keep_velocity: Option<bool>) {
+        let keep_velocity = keep_velocity.unwrap_or(false);
+        console::log_1(&format!("📏 Resize rescale {} (keep velocity: {})", if enabled { "on" } else { "off" }, keep_velocity).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetResizeRescale { enabled, keep_velocity });
+    }
+
+    /// Allow up to max_critters live critters; policy "block" drops spawns over the cap (default),
+    /// "recycle_oldest" despawns the oldest critter so fresh ones keep appearing
+    #[wasm_bindgen]
+    pub fn set_critter_cap(&self, max_critters: u32, policy: &str) -> Result<(), JsValue> {
+        let policy = resources::OverflowPolicy::parse(policy).map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🧮 Critter cap {} ({:?} when full)", max_critters, policy).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCritterCap { max_critters: max_critters.max(1) as usize, policy });
+        Ok(())
+    }
+
+    /// Switch between free play (critters spawn on a timer, the default) and guided play, where critters only
+    /// appear through spawn_critter()
+    #[wasm_bindgen]
+    pub fn set_auto_spawn(&self, enabled: bool) {
+        console::log_1(&format!("🎲 Auto-spawn: {}", if enabled { "on" } else { "off" }).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetAutoSpawn { enabled });
+    }
+
+    /// Spawn the selected critter now (guided play with auto-spawn off); the critter cap still applies
+    #[wasm_bindgen]
+    pub fn spawn_critter(&self) {
+        console::log_1(&"🐣 Manual critter spawn requested".into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SpawnCritter);
+    }
+
+    /// Hold each critter's catalog entry sound until its spawn-in animation finishes, so the sound lands as it
+    /// fully appears (off by default: the sound plays the moment it spawns)
+    #[wasm_bindgen]
+    pub fn set_entry_sound_after_spawn_in(&self, enabled: bool) {
+        console::log_1(&format!("🔊 Entry sound after spawn-in: {}", enabled).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetEntrySoundAfterSpawnIn { enabled });
+    }
+
+    /// Ignore catches on critters younger than this many ms (0 = catchable as soon as they spawn)
+    #[wasm_bindgen]
+    pub fn set_min_alive_ms(&self, ms: f32) {
+        console::log_1(&format!("⏳ Setting catch grace period: {}ms", ms).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetMinAliveMs { ms });
+    }
+
+    /// Despawn critters that stay this far off-screen for this many seconds (a cleanup safety net; 0 disables it)
+    #[wasm_bindgen]
+    pub fn set_offscreen_timeout(&self, secs: f32) {
+        console::log_1(&format!("🧹 Setting off-screen cleanup timeout: {}s", secs).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetOffscreenTimeout { secs });
+    }
+
+    /// Push overlapping critters apart with this strength (px/s^2); 0.0 disables separation
+    #[wasm_bindgen]
+    pub fn set_critter_separation(&self, strength: f32) {
+        console::log_1(&format!("↔️ Setting critter separation strength: {}", strength).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetSeparation { strength });
+    }
+
+    /// How hard curious critters (catalog `stats: (curiosity: ..)` above 0.5) are drawn toward the latest tap, in
+    /// px/s^2; off (0.0) by default
+    #[wasm_bindgen]
+    pub fn set_curiosity_strength(&self, strength: f32) {
+        console::log_1(&format!("🔍 Setting curiosity strength: {}", strength).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetCuriosity { strength });
+    }
+
+    /// Tune swipe detection for the input device; min_swipe_px keeps its current value when omitted
+    #[wasm_bindgen]
+    pub fn set_interaction_sensitivity(&self, sensitivity: f32, min_swipe_px: Option<f32>) {
+        console::log_1(&format!("👆 Setting interaction sensitivity: {} (min swipe: {:?}px)", sensitivity, min_swipe_px).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetInteractionSensitivity { sensitivity, min_swipe_px });
+    }
+
+    /// Tap calibration: `(dx, dy)` px are added to every reported pointer position before it's mapped into the
+    /// world, for embeddings whose canvas border/padding shifts taps consistently (0, 0 = off)
+    #[wasm_bindgen]
+    pub fn set_input_offset(&self, dx: f32, dy: f32) -> Result<(), JsValue> {
+        if !dx.is_finite() || !dy.is_finite() {
+            return Err(JsValue::from_str(&format!("Invalid input offset ({}, {})", dx, dy)));
+        }
+        console::log_1(&format!("🎯 Setting input offset: ({}, {})", dx, dy).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetInputOffset { dx, dy });
+        Ok(())
+    }
+
+    /// Posture label smoothing: majority vote over `window` frames, labels under the threshold read as "unknown"
+    #[wasm_bindgen]
+    pub fn set_posture_smoothing(&self, window: u32, confidence_threshold: f32) {
+        console::log_1(&format!("🤖 Setting posture smoothing: window={} threshold={}", window, confidence_threshold).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetPostureSmoothing { window, confidence_threshold });
+    }
+
+    /// Nearest-neighbour sampling for pixel art; target is "critters", "camera_preview", or omitted for both
+    #[wasm_bindgen]
+    pub fn set_pixel_perfect(&self, enabled: bool, target: Option<String>) -> Result<(), JsValue> {
+        let (critters, camera_preview) = match target.as_deref() {
+            None => (Some(enabled), Some(enabled)),
+            Some("critters") => (Some(enabled), None),
+            Some("camera_preview") => (None, Some(enabled)),
+            Some(other) => return Err(JsValue::from_str(&format!("Unknown pixel-perfect target: {}", other))),
+        };
+        console::log_1(&format!("🔍 Pixel-perfect sampling {} for {}", if enabled { "on" } else { "off" },
+            target.as_deref().unwrap_or("critters and camera preview")).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::SetPixelPerfect { critters, camera_preview });
+        Ok(())
+    }
+
+    /// Lift (or restore) the forced music-off override; later settings updates decide music_enabled
+    #[wasm_bindgen]
+    pub fn allow_music(&self, enabled: bool) {
+        console::log_1(&format!("🎵 Allow music override: {}", enabled).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::AllowMusic { enabled });
+    }
+
+    /// Restyle the score display, e.g. {"left_px":16,"top_px":16,"font_size":32,"color":"#FFD700","visible":true}
+    #[wasm_bindgen]
+    pub fn configure_ui(&self, config_json: &str) -> Result<(), JsValue> {
+        let config: resources::UiConfig = serde_json::from_str(config_json)
+            .map_err(|e| JsValue::from_str(&format!("Invalid UI config JSON: {}", e)))?;
+        config.text_color().map_err(|e| JsValue::from_str(&e))?;
+        console::log_1(&format!("🎨 Configuring UI: {:?}", config).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::ConfigureUi { config });
+        Ok(())
+    }
+
+    /// Start recording interactions and spawns against a fresh game (random seed if none given)
+    #[wasm_bindgen]
+    pub fn start_recording(&self, seed: Option<u32>) {
+        let seed = seed.map(u64::from).unwrap_or_else(|| u64::from(rand::random::<u32>()));
+        console::log_1(&format!("⏺️ Starting session recording (seed {})", seed).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::StartRecording { seed });
+    }
+
+    /// Stop recording; the session becomes available via get_last_recording()
+    #[wasm_bindgen]
+    pub fn stop_recording(&self) {
+        console::log_1(&"⏹️ Stopping session recording".into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::StopRecording);
+    }
+
+    /// Replay a recording exported by get_last_recording() against a fresh seeded game
+    #[wasm_bindgen]
+    pub fn play_recording(&self, recording_json: &str) -> Result<(), JsValue> {
+        let recording: resources::SessionRecording = serde_json::from_str(recording_json)
+            .map_err(|e| JsValue::from_str(&format!("Invalid recording JSON: {}", e)))?;
+        console::log_1(&format!("▶️ Playing session recording ({} events, seed {})",
+            recording.events.len(), recording.seed).into());
+
+        lock_queue(&GAME_CONTROL_QUEUE).push_back(GameControlRequest::StartPlayback { recording });
+        Ok(())
+    }
+
+    /// Set the camera preview debug filter ("None", "Grayscale", "Edges")
+    #[wasm_bindgen]
+    pub fn set_preview_filter(&self, mode: &str) {
+        console::log_1(&format!("📹 Setting camera preview filter: {}", mode).into());
+
+        lock_queue(&CAMERA_PREVIEW_QUEUE).push_back(CameraPreviewRequest::SetFilter {
+            mode: mode.to_string(),
+        });
+    }
 }
 
 /// Free functions to allow UI to query available critters without holding a GameEngine instance
@@ -455,6 +1451,7 @@ pub fn get_available_critters() -> js_sys::Array {
             let _ = js_sys::Reflect::set(&o, &"name".into(), &c.name.clone().into());
             let _ = js_sys::Reflect::set(&o, &"species".into(), &c.species.clone().into());
             let _ = js_sys::Reflect::set(&o, &"sprite".into(), &c.sprite_url.clone().into());
+            let _ = js_sys::Reflect::set(&o, &"unlocked".into(), &is_critter_unlocked(&c.id).into());
             // Animation/preview fields
             let _ = js_sys::Reflect::set(&o, &"frameWidth".into(), &c.frame_width.into());
             let _ = js_sys::Reflect::set(&o, &"frameHeight".into(), &c.frame_height.into());
@@ -479,6 +1476,198 @@ pub fn get_available_critters() -> js_sys::Array {
     arr
 }
 
+/// Ids of the critters the player has unlocked (the rest of get_available_critters() is locked)
+#[wasm_bindgen]
+pub fn get_unlocked_critters() -> js_sys::Array {
+    unlocked_critters().into_iter().map(JsValue::from).collect()
+}
+
+/// Critter catalog load status as JSON: {started, completed, error, empty, statWarnings}, where
+/// statWarnings lists critter stats that were out of range in the RON and got clamped
+#[wasm_bindgen]
+pub fn get_catalog_status() -> String {
+    serde_json::to_string(&catalog_status().unwrap_or_default()).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Tap heatmap as JSON: {cols, rows, counts}, raw tap counts row-major from the top-left cell
+/// (an empty default-size grid until the engine has started)
+#[wasm_bindgen]
+pub fn get_heatmap() -> String {
+    let heatmap = HEATMAP.lock().ok().and_then(|g| g.clone()).unwrap_or_default();
+    serde_json::to_string(&heatmap).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Catalog overview as JSON: {total, bySpecies: {"Bird": 5, ...}}; zero/empty until the catalog has loaded
+#[wasm_bindgen]
+pub fn get_catalog_summary() -> String {
+    serde_json::to_string(&catalog_summary().unwrap_or_default()).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Live state of the active critter, or null when none is spawned
+#[wasm_bindgen]
+pub fn get_current_critter_state() -> JsValue {
+    let Some(state) = current_critter_state() else { return JsValue::NULL; };
+    let o = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&o, &"id".into(), &state.id.into());
+    let _ = js_sys::Reflect::set(&o, &"x".into(), &state.x.into());
+    let _ = js_sys::Reflect::set(&o, &"y".into(), &state.y.into());
+    let _ = js_sys::Reflect::set(&o, &"happiness".into(), &state.happiness.into());
+    let _ = js_sys::Reflect::set(&o, &"energy".into(), &state.energy.into());
+    let _ = js_sys::Reflect::set(&o, &"speed".into(), &state.speed.into());
+    o.into()
+}
+
+/// Closest live critter to a world point as {id, x, y} (aim assist, "tap here" hints), or null when none are spawned
+#[wasm_bindgen]
+pub fn get_nearest_critter(world_x: f32, world_y: f32) -> JsValue {
+    let Some((id, pos)) = nearest_critter_position(Vec2::new(world_x, world_y)) else { return JsValue::NULL; };
+    let o = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&o, &"id".into(), &id.into());
+    let _ = js_sys::Reflect::set(&o, &"x".into(), &pos.x.into());
+    let _ = js_sys::Reflect::set(&o, &"y".into(), &pos.y.into());
+    o.into()
+}
+
+/// Start fetching catalog.ron and the critter RONs immediately (call as soon as the module is initialized,
+/// ahead of the first frame); the engine's startup picks up the result instead of fetching again
+#[wasm_bindgen]
+pub fn prefetch_catalog() {
+    if systems::prefetch_catalog() {
+        console::log_1(&"📦 Prefetching critter catalog ahead of engine startup...".into());
+    }
+}
+
+/// Bridge queue health ({recoveredLocks, pendingInteractions, pendingControls}) for "my tap did nothing" reports
+#[wasm_bindgen]
+pub fn get_queue_diagnostics() -> JsValue {
+    let o = js_sys::Object::new();
+    let recovered = QUEUE_LOCK_RECOVERIES.load(std::sync::atomic::Ordering::Relaxed);
+    let _ = js_sys::Reflect::set(&o, &"recoveredLocks".into(), &recovered.into());
+    let _ = js_sys::Reflect::set(&o, &"pendingInteractions".into(), &(lock_queue(&INTERACTION_QUEUE).len() as u32).into());
+    let _ = js_sys::Reflect::set(&o, &"pendingControls".into(), &(lock_queue(&GAME_CONTROL_QUEUE).len() as u32).into());
+    o.into()
+}
+
+/// Everything support usually asks for in one JSON blob: {liveCritters, mode, score, level, lives,
+/// audio, bluetooth, camera, queues}; read-only, for a "copy diagnostics" button
+#[wasm_bindgen]
+pub fn dump_diagnostics() -> Result<String, JsValue> {
+    let snapshot = diagnostics().ok_or_else(|| JsValue::from_str("Diagnostics not captured yet; the engine has not run a frame"))?;
+    let mut dump = serde_json::to_value(&snapshot).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+    dump["queues"] = serde_json::json!({
+        "recoveredLocks": QUEUE_LOCK_RECOVERIES.load(std::sync::atomic::Ordering::Relaxed),
+        "pendingInteractions": lock_queue(&INTERACTION_QUEUE).len(),
+        "pendingControls": lock_queue(&GAME_CONTROL_QUEUE).len(),
+    });
+    Ok(dump.to_string())
+}
+
+/// JSON snapshot of the live scene (game state, tuning, critter positions/velocities) for reproducing reported bugs
+#[wasm_bindgen]
+pub fn export_state() -> Result<String, JsValue> {
+    let scene = scene_snapshot().ok_or_else(|| JsValue::from_str("Scene not captured yet; the engine has not run a frame"))?;
+    serde_json::to_string(&scene).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Rebuild the scene from export_state() JSON on the next frame; current critters are replaced
+#[wasm_bindgen]
+pub fn import_state(state_json: &str) -> Result<(), JsValue> {
+    let scene: resources::SceneSnapshot = serde_json::from_str(state_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid scene snapshot: {}", e)))?;
+    if scene.version != resources::SceneSnapshot::VERSION {
+        return Err(JsValue::from_str(&format!("Unsupported scene snapshot version {}", scene.version)));
+    }
+    console::log_1(&format!("🧪 Importing scene with {} critter(s)", scene.critters.len()).into());
+    queue_scene_import(scene);
+    Ok(())
+}
+
+/// JSON of the most recently stopped session recording, if any
+#[wasm_bindgen]
+pub fn get_last_recording() -> Option<String> {
+    LAST_RECORDING.lock().ok().and_then(|g| g.clone())
+}
+
+/// Explosion renderer picked from the device's render adapter: "gpu" (hanabi particles) or "sprites" (WebGL2 fallback)
+#[wasm_bindgen]
+pub fn get_particle_backend() -> String {
+    PARTICLE_BACKEND.lock().map(|b| b.name()).unwrap_or("sprites").to_string()
+}
+
+/// Usage counters as JSON: {crittersSpawned, crittersCaught, crittersMissed, soundsPlayed, explosions, lives}
+/// (`lives` is null when unlimited)
+#[wasm_bindgen]
+pub fn get_telemetry() -> String {
+    serde_json::to_string(&telemetry()).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Current catch feedback as JSON: {level, particleScale, volumeScale, vibrationScale}. Collar Vibrate
+/// commands are scaled by the engine; navigator.vibrate callers scale their pattern by `vibrationScale`
+#[wasm_bindgen]
+pub fn get_feedback_intensity() -> String {
+    let level = FEEDBACK_INTENSITY.lock().map(|level| *level).unwrap_or_default();
+    serde_json::json!({
+        "level": level,
+        "particleScale": level.particle_scale(),
+        "volumeScale": level.volume_scale(),
+        "vibrationScale": level.vibration_scale(),
+    }).to_string()
+}
+
+/// Upgrade a stored player save (progress, high scores, achievements) of any known schema version to the
+/// current one, returned as JSON ready to write back; errors mean the blob can't be used (or is from a newer build)
+#[wasm_bindgen]
+pub fn migrate_save_data(json: &str) -> Result<String, JsValue> {
+    let migrated = save::migrate_save(json).map_err(|e| JsValue::from_str(&e))?;
+    if migrated.from_version != save::SAVE_SCHEMA_VERSION {
+        console::log_1(&format!("💾 Migrated save from schema v{} to v{}", migrated.from_version, save::SAVE_SCHEMA_VERSION).into());
+    }
+    if !migrated.discarded.is_empty() {
+        console::warn_1(&format!("⚠️ Discarded unreadable save sections: {}", migrated.discarded.join(", ")).into());
+    }
+    serde_json::to_string(&migrated.data).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Zero all usage counters (applied on the next frame)
+#[wasm_bindgen]
+pub fn reset_telemetry() {
+    console::log_1(&"📊 Resetting telemetry counters".into());
+    TELEMETRY_RESET_REQUESTED.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Camera preview settings as the engine currently applies them ({enabled, scale, anchor, mirrorX}), or null before startup
+#[wasm_bindgen]
+pub fn get_camera_preview_state() -> JsValue {
+    let Some(state) = camera_preview_state() else { return JsValue::NULL; };
+    let o = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&o, &"enabled".into(), &state.enabled.into());
+    let _ = js_sys::Reflect::set(&o, &"scale".into(), &state.scale.into());
+    let _ = js_sys::Reflect::set(&o, &"anchor".into(), &state.anchor.into());
+    let _ = js_sys::Reflect::set(&o, &"mirrorX".into(), &state.mirror_x.into());
+    o.into()
+}
+
+/// JSON array of known devices in a category, e.g. get_devices_by_type("SmartCollar")
+#[wasm_bindgen]
+pub fn get_devices_by_type(device_type: &str) -> Result<String, JsValue> {
+    let category = bluetooth::DeviceCategory::parse(device_type).map_err(|e| JsValue::from_str(&e))?;
+    let devices: Vec<bluetooth::DeviceInfo> = BLUETOOTH_DEVICES
+        .lock()
+        .map(|g| bluetooth::devices_in_category(&g, category))
+        .unwrap_or_default();
+    serde_json::to_string(&devices).map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Build timestamp and crate version ({timestamp, version}) for correlating bug reports
+#[wasm_bindgen]
+pub fn get_build_info() -> JsValue {
+    let info = build_info();
+    let o = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&o, &"timestamp".into(), &info.timestamp.into());
+    let _ = js_sys::Reflect::set(&o, &"version".into(), &info.version.into());
+    o.into()
+}
+
 /// Expose the JS->Bevy event sending function 
 #[wasm_bindgen]
 pub fn send_event_to_bevy(event_json: &str) -> Result<(), JsValue> {
@@ -491,80 +1680,143 @@ pub fn send_audio_response(response_json: &str) -> Result<(), JsValue> {
     send_audio_response_to_bevy(response_json)
 }
 
+/// Inject a synthetic Bluetooth response (JSON-encoded BluetoothLEResponse) for frontend testing
+#[wasm_bindgen]
+pub fn push_bluetooth_response(response_json: &str) -> Result<(), JsValue> {
+    send_bluetooth_response_to_bevy(response_json)
+}
+
 // Systems to process the event queues from WASM interface
 fn process_load_critter_queue(
     mut load_events: EventWriter<LoadCritterEvent>,
 ) {
-    if let Ok(mut queue) = LOAD_CRITTER_QUEUE.lock() {
-        while let Some(event) = queue.pop_front() {
-            load_events.write(event);
-        }
+    let mut queue = lock_queue(&LOAD_CRITTER_QUEUE);
+    while let Some(event) = queue.pop_front() {
+        load_events.write(event);
     }
 }
 
+// System to apply set_background_color() to the renderer's clear color
+fn apply_background_color(mut clear_color: ResMut<ClearColor>) {
+    if let Some(color) = BACKGROUND_COLOR_REQUEST.lock().ok().and_then(|mut g| g.take()) {
+        clear_color.0 = color;
+    }
+}
+
+// System to hand the latest reported pointer to the dwell tracker, in world space
+fn process_pointer_report(
+    mut tracker: ResMut<resources::DwellTracker>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    camera_view: Res<resources::CameraView>,
+    game_config: Res<resources::GameConfig>,
+) {
+    let Some((x, y)) = POINTER_REPORT.lock().ok().and_then(|mut pointer| pointer.take()) else { return };
+    let (Ok(window), Ok((camera, camera_transform))) = (window_query.single(), camera_query.single()) else { return };
+    let screen_pos = game_config.calibrated_screen_pos(x, y);
+    tracker.screen_pointer = Some(Vec2::new(x, y));
+    tracker.screen_size = Vec2::new(window.width(), window.height());
+    tracker.pointer = Some(camera.viewport_to_world_2d(camera_transform, screen_pos)
+        .unwrap_or_else(|_| camera_view.screen_to_world(screen_pos, Vec2::new(window.width(), window.height()))));
+}
+
 fn process_interaction_queue(
-    critter_query: Query<(Entity, &Transform), With<components::Critter>>,
+    critter_query: Query<(Entity, &Transform, Has<components::SpawnIn>, Has<components::Petting>), (With<components::Critter>, Without<components::CaughtReaction>, Without<components::Fleeing>, Without<components::FadingOut>)>,
+    game_config: Res<resources::GameConfig>,
     mut interaction_events: EventWriter<game::CritterInteractionEvent>,
     window_query: Query<&Window>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
     mut audio_gate: ResMut<resources::AudioGate>,
+    mut recent_tap: ResMut<resources::RecentTap>,
+    mut recorder: ResMut<resources::SessionRecorder>,
+    mut swipe_trails: EventWriter<effects::SwipeTrailEvent>,
+    camera_view: Res<resources::CameraView>,
+    mut telemetry: ResMut<resources::Telemetry>,
+    time: Res<Time>,
+    transition: Res<resources::ModeTransition>,
+    mut heatmap: ResMut<resources::Heatmap>,
 ) {
-    if let Ok(mut queue) = INTERACTION_QUEUE.lock() {
-        let queue_size = queue.len();
-        if queue_size > 0 {
-            console::log_1(&format!("🎯 Processing {} interactions from queue", queue_size).into());
+    let mut queue = lock_queue(&INTERACTION_QUEUE);
+    let queue_size = queue.len();
+    if queue_size > 0 {
+        console_log!("🎯 Processing {} interactions from queue", queue_size);
+    }
+    
+    while let Some((interaction, screen_x, screen_y)) = queue.pop_front() {
+        // Convert screen coordinates to world coordinates
+        let Ok(window) = window_query.single() else { continue; };
+        let Ok((camera, camera_transform)) = camera_query.single() else { continue; };
+        
+        // Convert screen position (corrected by the calibration offset) to world position
+        let screen_pos = game_config.calibrated_screen_pos(screen_x, screen_y);
+        if !systems::admit_interaction(&interaction, Vec2::new(screen_x, screen_y), screen_pos,
+            Vec2::new(window.width(), window.height()), time.elapsed_secs_f64(), &transition, &mut recorder, &mut heatmap) {
+            continue;
         }
+        let interaction = interaction.with_sensitivity(game_config.interaction_sensitivity, game_config.min_swipe_px);
+        let world_pos = if let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, screen_pos) {
+            world_position
+        } else {
+            // Fallback: convert through the configured pan/zoom
+            camera_view.screen_to_world(screen_pos, Vec2::new(window.width(), window.height()))
+        };
         
-        while let Some((interaction_type, screen_x, screen_y, _dir_x, _dir_y)) = queue.pop_front() {
-            // Convert screen coordinates to world coordinates
-            let Ok(window) = window_query.single() else { continue; };
-            let Ok((camera, camera_transform)) = camera_query.single() else { continue; };
-            
-            // Convert screen position to world position
-            let screen_pos = Vec2::new(screen_x, screen_y);
-            let world_pos = if let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, screen_pos) {
-                world_position
-            } else {
-                // Fallback: simple conversion assuming centered camera
-                Vec2::new(screen_x - window.width() / 2.0, window.height() / 2.0 - screen_y)
-            };
-            
-            console::log_1(&format!("🎯 Click at screen ({}, {}) -> world ({}, {})", 
-                screen_x, screen_y, world_pos.x, world_pos.y).into());
-            
-            // Find the closest critter to the click position  
-            // Unlock audio due to user gesture
-            audio_gate.enabled = true;
-            
-            let critter_count = critter_query.iter().count();
-            console::log_1(&format!("🎯 Found {} critters in scene", critter_count).into());
-            
-            for (entity, transform) in &critter_query {
-                let critter_pos = transform.translation.xy();
-                let critter_size = 100.0; // Larger clickable area radius for easier clicking
-                let distance = world_pos.distance(critter_pos);
-                
-                console::log_1(&format!("🎯 Distance to critter at ({}, {}): {:.1}", 
-                    critter_pos.x, critter_pos.y, distance).into());
-                
-                if distance <= critter_size {
-                    let interaction_type_enum = match interaction_type.as_str() {
-                        "swipe" => game::InteractionType::Swipe(Vec2::ZERO), // Could use dir_x, dir_y
-                        "hold" => game::InteractionType::Hold,
-                        _ => game::InteractionType::Tap, // Default to tap
-                    };
-                    
+        console_log!("🎯 Click at screen ({}, {}) -> world ({}, {})", 
+            screen_x, screen_y, world_pos.x, world_pos.y);
+        
+        // Releasing completes the pet wherever the finger lifts
+        if interaction == game::InteractionType::Pet(game::PetPhase::Release) {
+            for (entity, _, _, petting) in &critter_query {
+                if petting {
                     interaction_events.write(game::CritterInteractionEvent {
                         critter_entity: entity,
-                        interaction_type: interaction_type_enum,
+                        interaction_type: interaction,
                         position: world_pos,
                     });
-                    
-                    console::log_1(&format!("✅ {} interaction sent to critter at ({}, {})", 
-                        interaction_type, critter_pos.x, critter_pos.y).into());
-                    break; // Only interact with the first critter found
                 }
             }
+            continue;
+        }
+        
+        // Immediate feedback along the swipe, before any critter reacts (screen y points down)
+        if let game::InteractionType::Swipe(delta) = interaction {
+            swipe_trails.write(effects::SwipeTrailEvent { origin: world_pos, direction: Vec2::new(delta.x, -delta.y) });
+        }
+        
+        // Remember where the pet touched for the magnet assist
+        recent_tap.position = Some(world_pos);
+        recent_tap.age_secs = 0.0;
+        
+        // Find the closest critter to the click position  
+        // Unlock audio due to user gesture
+        audio_gate.enabled = true;
+        
+        let critter_count = critter_query.iter().count();
+        console_log!("🎯 Found {} critters in scene", critter_count);
+        
+        let candidates = critter_query
+            .iter()
+            .filter(|(_, _, spawning_in, _)| !spawning_in || game_config.interactable_during_spawn_in)
+            .map(|(entity, transform, _, _)| (entity, transform.translation.xy()));
+        let mut hit = false;
+        if let Some((entity, critter_pos, distance)) = systems::nearest_critter(world_pos, candidates) {
+            console_log!("🎯 Nearest critter at ({}, {}): {:.1}", 
+                critter_pos.x, critter_pos.y, distance);
+            
+            if distance <= systems::TAP_HIT_RADIUS {
+                interaction_events.write(game::CritterInteractionEvent {
+                    critter_entity: entity,
+                    interaction_type: interaction,
+                    position: world_pos,
+                });
+                
+                console_log!("✅ {} interaction sent to critter at ({}, {})", 
+                    interaction.name(), critter_pos.x, critter_pos.y);
+                hit = true;
+            }
+        }
+        if !hit && !matches!(interaction, game::InteractionType::Pet(_)) {
+            telemetry.critters_missed += 1;
         }
     }
 }
@@ -573,10 +1825,9 @@ fn process_interaction_queue(
 fn process_audio_event_queue(
     mut bevy_to_js_events: EventWriter<BevyToJsEvent>,
 ) {
-    if let Ok(mut queue) = AUDIO_EVENT_QUEUE.lock() {
-        while let Some(event) = queue.pop_front() {
-            bevy_to_js_events.write(event);
-        }
+    let mut queue = lock_queue(&AUDIO_EVENT_QUEUE);
+    while let Some(event) = queue.pop_front() {
+        bevy_to_js_events.write(event);
     }
 }
 
@@ -584,10 +1835,9 @@ fn process_audio_event_queue(
 fn process_native_audio_queue(
     mut audio_requests: EventWriter<audio::AudioRequest>,
 ) {
-    if let Ok(mut queue) = NATIVE_AUDIO_QUEUE.lock() {
-        while let Some(request) = queue.pop_front() {
-            audio_requests.write(request);
-        }
+    let mut queue = lock_queue(&NATIVE_AUDIO_QUEUE);
+    while let Some(request) = queue.pop_front() {
+        audio_requests.write(request);
     }
 }
 
@@ -595,24 +1845,123 @@ fn process_native_audio_queue(
 fn process_bluetooth_request_queue(
     mut bluetooth_requests: EventWriter<BluetoothRequest>,
 ) {
-    if let Ok(mut queue) = BLUETOOTH_REQUEST_QUEUE.lock() {
-        while let Some(request) = queue.pop_front() {
-            bluetooth_requests.write(request);
-        }
+    let mut queue = lock_queue(&BLUETOOTH_REQUEST_QUEUE);
+    while let Some(request) = queue.pop_front() {
+        bluetooth_requests.write(request);
+    }
+}
+
+// System to mirror the Bluetooth manager's device list for the JS snapshot API
+fn publish_bluetooth_devices(bt: Res<bluetooth::BluetoothLEManager>) {
+    if !bt.is_changed() {
+        return;
+    }
+    if let Ok(mut devices) = BLUETOOTH_DEVICES.lock() {
+        *devices = bt.all_devices();
+    }
+}
+
+// System to mirror the camera preview control for get_camera_preview_state()
+fn publish_camera_preview_state(ctrl: Res<camera::CameraPreviewControl>) {
+    if !ctrl.is_changed() {
+        return;
+    }
+    if let Ok(mut state) = CAMERA_PREVIEW_STATE.lock() {
+        *state = Some(CameraPreviewSnapshot {
+            enabled: ctrl.enabled,
+            scale: ctrl.scale,
+            anchor: ctrl.anchor.name().to_string(),
+            mirror_x: ctrl.mirror_x,
+        });
+    }
+}
+
+pub(crate) fn camera_preview_state() -> Option<CameraPreviewSnapshot> {
+    CAMERA_PREVIEW_STATE.lock().ok().and_then(|g| g.clone())
+}
+
+// System to apply reset_telemetry() and mirror the counters for get_telemetry()
+fn publish_telemetry(mut telemetry: ResMut<resources::Telemetry>) {
+    if TELEMETRY_RESET_REQUESTED.swap(false, std::sync::atomic::Ordering::SeqCst) {
+        *telemetry = resources::Telemetry::default();
+    }
+    if !telemetry.is_changed() {
+        return;
+    }
+    if let Ok(mut snapshot) = TELEMETRY.lock() {
+        *snapshot = *telemetry;
+    }
+}
+
+// System to refresh the dump_diagnostics() summary (a handful of counters, so every frame is fine)
+fn publish_diagnostics(
+    live_critters: Query<(), systems::LiveCritterFilter>,
+    game_state: Res<game::GameState>,
+    audio_gate: Res<resources::AudioGate>,
+    audio_manager: Option<Res<audio::AudioManager>>,
+    request_tracker: Option<Res<requests::RequestTracker>>,
+    bt: Option<Res<bluetooth::BluetoothLEManager>>,
+    camera_stats: Option<Res<camera::CameraStats>>,
+) {
+    let audio = audio_manager.map(|manager| AudioDiagnostics {
+        gate_enabled: audio_gate.enabled,
+        manager_unlocked: manager.gesture_enabled,
+        muted: manager.muted,
+        playing: manager.playing_sounds.len(),
+        pending: request_tracker.as_ref().map_or(0, |tracker| tracker.pending_count("audio")),
+        error_count: manager.error_count,
+    }).unwrap_or(AudioDiagnostics { gate_enabled: audio_gate.enabled, ..default() });
+    let bluetooth = bt.map(|bt| BluetoothDiagnostics {
+        scanning: bt.scanning,
+        connected_devices: bt.connected_devices.len(),
+        discovered_devices: bt.discovered_devices.len(),
+        virtual_network_enabled: bt.virtual_network_enabled,
+        error_count: bt.error_count,
+    }).unwrap_or_default();
+    let camera = camera_stats.map(|stats| CameraDiagnostics {
+        total_frames: stats.total_frames,
+        throttled_frames: stats.throttled_frames,
+        dropped_frames: stats.dropped_frames,
+        fps: stats.fps,
+        last_frame_size: stats.last_frame_size.map(|size| [size.x, size.y]),
+    }).unwrap_or_default();
+    let snapshot = DiagnosticsSnapshot {
+        live_critters: live_critters.iter().count(),
+        mode: game_state.game_mode.name(),
+        score: game_state.score,
+        level: game_state.level,
+        lives: game_state.lives,
+        audio,
+        bluetooth,
+        camera,
+    };
+    if let Ok(mut g) = DIAGNOSTICS.lock() {
+        *g = Some(snapshot);
     }
 }
 
-// System to process Bluetooth responses and forward to WASM interface
+// System to mirror the tap heatmap for get_heatmap()
+fn publish_heatmap(heatmap: Res<resources::Heatmap>) {
+    if !heatmap.is_changed() {
+        return;
+    }
+    if let Ok(mut snapshot) = HEATMAP.lock() {
+        *snapshot = Some(heatmap.clone());
+    }
+}
+
+pub(crate) fn telemetry() -> resources::Telemetry {
+    TELEMETRY.lock().map(|t| *t).unwrap_or_default()
+}
+
+// System to log Bluetooth responses (JS reads device state through publish_bluetooth_devices)
 fn process_bluetooth_response_queue(
+    bt: Res<bluetooth::BluetoothLEManager>,
     mut bluetooth_responses: EventReader<BluetoothResponse>,
 ) {
     for response in bluetooth_responses.read() {
-        // Forward responses to JavaScript via event system or store in queue
-        // For now, just log them
-        console::log_1(&format!("🔵 Bluetooth response: {:?}", response).into());
-        
-        if let Ok(mut queue) = BLUETOOTH_RESPONSE_QUEUE.lock() {
-            queue.push_back(response.clone());
+        if bt.log_enabled(response.is_error()) {
+            bluetooth::bluetooth_log(format!("🔵 Bluetooth response: {:?}", response));
         }
     }
 }
@@ -621,26 +1970,694 @@ fn process_bluetooth_response_queue(
 fn process_camera_preview_queue(
     mut preview_control: ResMut<camera::CameraPreviewControl>,
 ) {
-    if let Ok(mut queue) = CAMERA_PREVIEW_QUEUE.lock() {
-        while let Some(request) = queue.pop_front() {
-            match request {
-                CameraPreviewRequest::Enable { scale, anchor } => {
-                    console::log_1(&format!("📹 Processing camera preview enable: scale={}, anchor={}", scale, anchor).into());
-                    preview_control.enabled = true;
-                    preview_control.scale = scale;
-                    preview_control.anchor = match anchor.as_str() {
-                        "TopLeft" => camera::PreviewAnchor::TopLeft,
-                        "TopRight" => camera::PreviewAnchor::TopRight,
-                        "BottomLeft" => camera::PreviewAnchor::BottomLeft,
-                        "BottomRight" => camera::PreviewAnchor::BottomRight,
-                        _ => camera::PreviewAnchor::TopRight, // default
-                    };
+    let mut queue = lock_queue(&CAMERA_PREVIEW_QUEUE);
+    while let Some(request) = queue.pop_front() {
+        match request {
+            CameraPreviewRequest::Enable { scale, anchor } => {
+                console_log!("📹 Processing camera preview enable: scale={}, anchor={}", scale, anchor);
+                preview_control.enabled = true;
+                preview_control.scale = scale;
+                preview_control.anchor = camera::PreviewAnchor::from_name(&anchor);
+            }
+            CameraPreviewRequest::Disable => {
+                console_log!("📹 Processing camera preview disable");
+                preview_control.enabled = false;
+            }
+            CameraPreviewRequest::SetFilter { mode } => {
+                match camera::PreviewFilter::from_str(&mode) {
+                    Some(filter) => {
+                        console_log!("📹 Processing camera preview filter: {:?}", filter);
+                        preview_control.filter = filter;
+                    }
+                    None => {
+                        console_warn!("📹 Unknown preview filter: {}", mode);
+                    }
                 }
-                CameraPreviewRequest::Disable => {
-                    console::log_1(&"📹 Processing camera preview disable".into());
-                    preview_control.enabled = false;
+            }
+            CameraPreviewRequest::SelectSource { source_id } => {
+                console_log!("📹 Processing camera preview source: {}", source_id);
+                preview_control.source_id = source_id;
+            }
+            CameraPreviewRequest::SetSafeAreaInsets { top, right, bottom, left } => {
+                let clean = |v: f32| if v.is_finite() { v.max(0.0) } else { 0.0 };
+                preview_control.safe_area = camera::SafeAreaInsets {
+                    top: clean(top),
+                    right: clean(right),
+                    bottom: clean(bottom),
+                    left: clean(left),
+                };
+            }
+            CameraPreviewRequest::SetLayers { preview_z, overlay_z } => {
+                let (preview_z, overlay_z) = camera::preview_layers(preview_z, overlay_z);
+                preview_control.preview_z = preview_z;
+                preview_control.overlay_z = overlay_z;
+            }
+            CameraPreviewRequest::SetPreviewFps { fps } => {
+                preview_control.preview_fps = fps;
+            }
+        }
+    }
+}
+
+// System to process celebration triggers from WASM interface
+fn process_celebration_queue(
+    mut celebrations: EventWriter<game::CelebrateEvent>,
+) {
+    let mut queue = lock_queue(&CELEBRATION_QUEUE);
+    while let Some(position) = queue.pop_front() {
+        celebrations.write(game::CelebrateEvent { position });
+    }
+}
+
+// System to move gameplay tuning requests from the WASM interface onto the event stream, one reader per area
+fn process_game_control_queue(
+    mut requests: EventWriter<GameControlRequest>,
+) {
+    let mut queue = lock_queue(&GAME_CONTROL_QUEUE);
+    while let Some(request) = queue.pop_front() {
+        requests.write(request);
+    }
+}
+
+/// Spawning and critter tuning requests
+fn apply_critter_controls(
+    mut requests: EventReader<GameControlRequest>,
+    mut game_config: ResMut<resources::GameConfig>,
+    mut spawn_rotation: ResMut<resources::SpawnRotation>,
+    mut critter_registry: Option<ResMut<resources::CritterRegistry>>,
+    mut dwell: ResMut<resources::DwellConfig>,
+    mut assist: ResMut<resources::AssistConfig>,
+    mut escape: ResMut<resources::EscapeConfig>,
+    (mut tutorial, mut practice, mut critter_switch, mut interaction_cancel): (ResMut<resources::TutorialState>, ResMut<resources::PracticeState>, ResMut<resources::CritterSwitch>, ResMut<resources::InteractionCancel>),
+    game_state: Res<game::GameState>,
+    mut spawns: EventWriter<SpawnCritterEvent>,
+) {
+    for request in requests.read() {
+        match request.clone() {
+            GameControlRequest::SetTimeScale { scale } => {
+                game_config.time_scale = resources::GameConfig::clamp_time_scale(scale);
+            }
+            GameControlRequest::SetMaxFrameDelta { secs } => {
+                game_config.max_frame_delta_secs = resources::GameConfig::clamp_max_frame_delta(secs);
+            }
+            GameControlRequest::SetDwellTime { secs } => {
+                dwell.dwell_secs = if secs.is_finite() { secs.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetMagnetAssist { strength } => {
+                assist.magnet_strength = if strength.is_finite() { strength.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetSpawnJitter { fraction } => {
+                game_config.spawn_jitter = if fraction.is_finite() {
+                    fraction.clamp(0.0, resources::GameConfig::MAX_SPAWN_JITTER)
+                } else {
+                    0.0
+                };
+            }
+            GameControlRequest::SetResizeRescale { enabled, keep_velocity } => {
+                game_config.rescale_on_resize = enabled;
+                game_config.keep_velocity_on_resize = keep_velocity;
+            }
+            GameControlRequest::SetSpawnRotation { enabled, weights } => {
+                spawn_rotation.enabled = enabled;
+                if let Some(weights) = weights {
+                    spawn_rotation.weights = weights;
+                }
+            }
+            GameControlRequest::SetCritterCap { max_critters, policy } => {
+                game_config.max_critters = max_critters;
+                game_config.overflow_policy = policy;
+            }
+            GameControlRequest::SetEntrySoundAfterSpawnIn { enabled } => {
+                game_config.entry_sound_after_spawn_in = enabled;
+            }
+            GameControlRequest::SetAutoSpawn { enabled } => {
+                game_config.auto_spawn = enabled;
+            }
+            GameControlRequest::SpawnCritter => {
+                if game_state.selected_critter_id.is_none() {
+                    console_warn!("⚠️ spawn_critter: no critter selected yet");
+                } else {
+                    // The spawning system still applies the critter cap
+                    spawns.write(SpawnCritterEvent { position: Vec2::ZERO });
+                }
+            }
+            GameControlRequest::SetMinAliveMs { ms } => {
+                game_config.min_alive_ms = if ms.is_finite() { ms.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetOffscreenTimeout { secs } => {
+                game_config.offscreen_timeout_secs = if secs.is_finite() { secs.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetCatalogTimeout { secs } => {
+                game_config.catalog_timeout_secs = if secs.is_finite() { secs.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetSeparation { strength } => {
+                game_config.separation_strength = if strength.is_finite() { strength.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetCuriosity { strength } => {
+                game_config.curiosity_strength = if strength.is_finite() { strength.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SetInteractionSensitivity { sensitivity, min_swipe_px } => {
+                if sensitivity.is_finite() && sensitivity > 0.0 {
+                    game_config.interaction_sensitivity = sensitivity;
+                }
+                if let Some(px) = min_swipe_px.filter(|px| px.is_finite()) {
+                    game_config.min_swipe_px = px.max(0.0);
+                }
+            }
+            GameControlRequest::SetInputOffset { dx, dy } => {
+                game_config.input_offset = Vec2::new(dx, dy);
+            }
+            GameControlRequest::SpawnTutorialCritter { critter_id } => {
+                match critter_registry.as_ref() {
+                    Some(registry) if registry.catalog.critters.contains_key(&critter_id) => {
+                        tutorial.requested = Some(critter_id);
+                    }
+                    _ => console_warn!("⚠️ Cannot start tutorial with unknown critter: {}", critter_id),
+                }
+            }
+            GameControlRequest::SpawnPracticeCritter { critter_id } => {
+                match critter_registry.as_ref() {
+                    Some(registry) if registry.catalog.critters.contains_key(&critter_id) => {
+                        practice.requested = Some(critter_id);
+                    }
+                    _ => console_warn!("⚠️ Cannot spawn unknown practice critter: {}", critter_id),
                 }
             }
+            GameControlRequest::SwitchCritter { critter_id, replace_current } => {
+                match critter_registry.as_ref() {
+                    Some(registry) if registry.catalog.critters.contains_key(&critter_id) => {
+                        critter_switch.requested = Some(critter_id);
+                        critter_switch.replace_current = replace_current;
+                    }
+                    _ => console_warn!("⚠️ Cannot switch to unknown critter: {}", critter_id),
+                }
+            }
+            GameControlRequest::CancelInteraction => {
+                interaction_cancel.requested = true;
+            }
+            GameControlRequest::DismissTutorial => {
+                tutorial.dismiss_requested = tutorial.is_active();
+            }
+            GameControlRequest::UnlockCritter { critter_id } => {
+                match critter_registry.as_mut() {
+                    Some(registry) if registry.catalog.critters.contains_key(&critter_id) => {
+                        registry.unlock(&critter_id);
+                    }
+                    Some(_) => console_warn!("⚠️ Cannot unlock unknown critter: {}", critter_id),
+                    None => console_warn!("⚠️ Critter registry not ready; unlock of {} dropped", critter_id),
+                }
+            }
+            GameControlRequest::SetCritterTint { critter_id, tint } => {
+                match critter_registry.as_mut() {
+                    Some(registry) if registry.catalog.critters.contains_key(&critter_id) => match tint {
+                        Some([r, g, b, a]) => { registry.tints.insert(critter_id, resources::tint_color(r, g, b, a)); }
+                        None => { registry.tints.remove(&critter_id); }
+                    },
+                    Some(_) => console_warn!("⚠️ Cannot tint unknown critter: {}", critter_id),
+                    None => console_warn!("⚠️ Critter registry not ready; tint for {} dropped", critter_id),
+                }
+            }
+            GameControlRequest::SetEscape { timeout_secs, animate, sound_id } => {
+                escape.timeout_secs = if timeout_secs.is_finite() { timeout_secs.max(0.0) } else { 0.0 };
+                escape.animate = animate;
+                escape.sound_id = sound_id;
+            }
+            _ => {}
         }
     }
 }
+
+/// Sound, music and catch-feedback requests
+fn apply_feedback_controls(
+    mut requests: EventReader<GameControlRequest>,
+    mut feedback: ResMut<resources::FeedbackIntensity>,
+    mut despawn_styles: ResMut<resources::DespawnStyleConfig>,
+    mut explosion_config: ResMut<effects::ExplosionConfig>,
+    mut explosion_sound: ResMut<effects::ExplosionSoundConfig>,
+    mut spawn_sound: ResMut<resources::SpawnSoundConfig>,
+    mut music_policy: ResMut<events::MusicPolicy>,
+    mut shared_settings: ResMut<events::SharedSettings>,
+) {
+    for request in requests.read() {
+        match request.clone() {
+            GameControlRequest::SetFeedbackIntensity { level } => {
+                *feedback = level;
+                if let Ok(mut current) = FEEDBACK_INTENSITY.lock() {
+                    *current = level;
+                }
+            }
+            GameControlRequest::SetDespawnStyle { intensity, style } => {
+                despawn_styles.set(intensity, style);
+            }
+            GameControlRequest::SetMaxExplosions { max } => {
+                explosion_config.max_concurrent = max as usize;
+            }
+            GameControlRequest::SetExplosionSound { sound_id, critter_id } => {
+                match (critter_id, sound_id) {
+                    (Some(critter_id), Some(sound_id)) => { explosion_sound.per_critter.insert(critter_id, sound_id); }
+                    (Some(critter_id), None) => { explosion_sound.per_critter.remove(&critter_id); }
+                    (None, sound_id) => explosion_sound.default_sound = sound_id,
+                }
+            }
+            GameControlRequest::SetSpawnSound { enabled, sound_id } => {
+                spawn_sound.enabled = enabled;
+                if sound_id.is_some() {
+                    spawn_sound.sound_id = sound_id;
+                }
+            }
+            GameControlRequest::AllowMusic { enabled } => {
+                music_policy.allow_music = enabled;
+                if !enabled {
+                    shared_settings.music_enabled = false;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Camera, overlay and UI requests
+fn apply_view_controls(
+    mut requests: EventReader<GameControlRequest>,
+    mut camera_view: ResMut<resources::CameraView>,
+    mut posture: ResMut<camera::PostureRecognitionState>,
+    mut pixel_perfect: ResMut<resources::PixelPerfect>,
+    mut heatmap: ResMut<resources::Heatmap>,
+    mut swipe_trail: ResMut<effects::SwipeTrailConfig>,
+    mut ui_config: ResMut<resources::UiConfig>,
+) {
+    for request in requests.read() {
+        match request.clone() {
+            GameControlRequest::SetPostureSmoothing { window, confidence_threshold } => {
+                posture.smoothing_window = (window as usize).max(1);
+                if confidence_threshold.is_finite() {
+                    posture.confidence_threshold = confidence_threshold.clamp(0.0, 1.0);
+                }
+            }
+            GameControlRequest::SetPixelPerfect { critters, camera_preview } => {
+                if let Some(critters) = critters {
+                    pixel_perfect.critters = critters;
+                }
+                if let Some(camera_preview) = camera_preview {
+                    pixel_perfect.camera_preview = camera_preview;
+                }
+            }
+            GameControlRequest::SetCamera { x, y, zoom } => {
+                if x.is_finite() && y.is_finite() {
+                    camera_view.position = Vec2::new(x, y);
+                }
+                camera_view.zoom = resources::CameraView::clamp_zoom(zoom);
+                if let Ok(mut view) = CAMERA_VIEW.lock() {
+                    *view = *camera_view;
+                }
+            }
+            GameControlRequest::SetHeatmapResolution { cols, rows } => {
+                *heatmap = resources::Heatmap::new(cols, rows);
+            }
+            GameControlRequest::SetSwipeTrail { enabled } => {
+                swipe_trail.enabled = enabled;
+            }
+            GameControlRequest::ConfigureUi { config } => {
+                *ui_config = config;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Game mode, lives, mode transitions and recording/playback requests
+fn apply_session_controls(
+    mut requests: EventReader<GameControlRequest>,
+    mut game_state: ResMut<game::GameState>,
+    mut game_config: ResMut<resources::GameConfig>,
+    mut transition: ResMut<resources::ModeTransition>,
+    mut countdown: ResMut<resources::Countdown>,
+    mut recorder: ResMut<resources::SessionRecorder>,
+    mut game_rng: ResMut<resources::GameRng>,
+    critter_query: Query<Entity, With<components::Critter>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    // Recording and playback both start from a fresh game with the session seed
+    let mut start_fresh = |seed: u64, game_state: &mut game::GameState| {
+        game_rng.reseed(seed);
+        for entity in &critter_query {
+            commands.entity(entity).despawn();
+        }
+        game_state.score = 0;
+        game_state.level = 0;
+        game_state.current_critter_id = None;
+    };
+
+    for request in requests.read() {
+        match request.clone() {
+            GameControlRequest::SetStartingLives { lives } => {
+                game_config.starting_lives = lives;
+                game_state.lives = lives;
+            }
+            GameControlRequest::StartTimedSession { seconds } => {
+                // Score only counts from the start of the clock
+                game_state.score = 0;
+                game_state.lives = game_config.starting_lives;
+                game_state.set_mode(game::GameMode::Playing);
+                publish_game_mode(game::GameMode::Playing);
+                countdown.start(seconds);
+            }
+            GameControlRequest::SetTransitionDuration { secs } => {
+                transition.duration_secs = if secs.is_finite() { secs.max(0.0) } else { 0.0 };
+            }
+            GameControlRequest::SkipTransition => {
+                transition.skip();
+            }
+            GameControlRequest::SetGameMode { mode } => {
+                // A fresh game (not resuming from pause) starts with full lives
+                if mode == game::GameMode::Playing && matches!(game_state.game_mode, game::GameMode::Menu | game::GameMode::GameOver) {
+                    game_state.lives = game_config.starting_lives;
+                }
+                game_state.set_mode(mode);
+                publish_game_mode(mode);
+            }
+            GameControlRequest::StartRecording { seed } => {
+                start_fresh(seed, &mut game_state);
+                recorder.start_recording(seed, time.elapsed_secs_f64());
+            }
+            GameControlRequest::StopRecording => {
+                if !recorder.is_recording() {
+                    console_warn!("⏹️ stop_recording called while not recording");
+                    continue;
+                }
+                let recording = recorder.stop();
+                console_log!("⏹️ Recorded {} events", recording.events.len());
+                match serde_json::to_string(&recording) {
+                    Ok(json) => {
+                        if let Ok(mut last) = LAST_RECORDING.lock() {
+                            *last = Some(json);
+                        }
+                    }
+                    Err(e) => console_error!("❌ Failed to serialize recording: {}", e),
+                }
+            }
+            GameControlRequest::StartPlayback { recording } => {
+                start_fresh(recording.seed, &mut game_state);
+                recorder.start_playback(recording, time.elapsed_secs_f64());
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlocked_critters_follow_registry() {
+        let registry = resources::CritterRegistry::from_ron("(critters: {})", String::new()).expect("empty catalog parses");
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(registry)
+            .add_systems(Update, systems::unlocked_critters_snapshot_system);
+        app.update();
+        assert!(unlocked_critters().contains(&"chirpy_bird".to_string()));
+        assert!(is_critter_unlocked("chirpy_bird"));
+        assert!(!is_critter_unlocked("rare_bunny"));
+
+        assert!(app.world_mut().resource_mut::<resources::CritterRegistry>().unlock("rare_bunny"));
+        assert!(!app.world_mut().resource_mut::<resources::CritterRegistry>().unlock("rare_bunny"), "already unlocked");
+        app.update();
+        assert!(unlocked_critters().contains(&"rare_bunny".to_string()));
+        assert!(is_critter_unlocked("rare_bunny"));
+    }
+
+    #[test]
+    fn test_diagnostics_dump_summarizes_the_game() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(game::GameState { score: 150, level: 2, lives: Some(3), ..default() })
+            .insert_resource(resources::AudioGate { enabled: true, ..Default::default() })
+            .init_resource::<audio::AudioManager>()
+            .init_resource::<bluetooth::BluetoothLEManager>()
+            .insert_resource(camera::CameraStats { total_frames: 90, fps: 29.5, last_frame_size: Some(UVec2::new(640, 480)), ..default() })
+            .add_systems(Update, publish_diagnostics);
+        app.world_mut().resource_mut::<game::GameState>().set_mode(game::GameMode::Playing);
+        for x in [-100.0, 100.0] {
+            app.world_mut().spawn((
+                test_support::test_critter("Hoppy", components::CritterSpecies::Bunny),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ));
+        }
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let dump: serde_json::Value = serde_json::from_str(&dump_diagnostics().expect("captured")).expect("valid JSON");
+        for key in ["liveCritters", "mode", "score", "level", "lives", "audio", "bluetooth", "camera", "queues"] {
+            assert!(dump.get(key).is_some(), "missing {} in {}", key, dump);
+        }
+        assert_eq!(dump["liveCritters"], 2);
+        assert_eq!(dump["mode"], "playing");
+        assert_eq!((dump["score"].as_u64(), dump["level"].as_u64(), dump["lives"].as_u64()), (Some(150), Some(2), Some(3)));
+        assert_eq!(dump["audio"]["gateEnabled"], true);
+        assert_eq!(dump["bluetooth"]["connectedDevices"], 0);
+        assert_eq!(dump["camera"]["totalFrames"], 90);
+        assert_eq!(dump["camera"]["lastFrameSize"], serde_json::json!([640, 480]));
+    }
+
+    #[test]
+    fn test_background_color_updates_clear_color() {
+        assert!(background_color(1.2, 0.0, 0.0).is_err());
+        assert!(background_color(0.5, -0.1, 0.0).is_err());
+        assert!(background_color(0.0, 0.0, f32::NAN).is_err());
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ClearColor>()
+            .add_systems(Update, apply_background_color);
+        app.update();
+        let before = app.world().resource::<ClearColor>().0;
+
+        queue_background_color(background_color(0.1, 0.2, 0.3).expect("valid color"));
+        app.update();
+        let color = app.world().resource::<ClearColor>().0;
+        assert_ne!(color, before);
+        assert_eq!(color, Color::srgb(0.1, 0.2, 0.3));
+    }
+
+    #[test]
+    fn test_camera_preview_snapshot_follows_control() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<camera::CameraPreviewControl>()
+            .add_systems(Update, publish_camera_preview_state);
+        app.update();
+        assert_eq!(camera_preview_state().map(|s| s.anchor), Some("TopRight".to_string()));
+
+        // Same change enable_camera_preview(0.75, "BottomLeft") queues
+        {
+            let mut ctrl = app.world_mut().resource_mut::<camera::CameraPreviewControl>();
+            ctrl.enabled = true;
+            ctrl.scale = 0.75;
+            ctrl.anchor = camera::PreviewAnchor::from_name("BottomLeft");
+        }
+        app.update();
+        assert_eq!(camera_preview_state(), Some(CameraPreviewSnapshot {
+            enabled: true,
+            scale: 0.75,
+            anchor: "BottomLeft".to_string(),
+            mirror_x: false,
+        }));
+    }
+
+    #[test]
+    fn test_heatmap_accumulates_taps_per_cell() {
+        let mut heatmap = resources::Heatmap::new(4, 2);
+        let screen = Vec2::new(400.0, 200.0);
+        heatmap.record(Vec2::new(10.0, 10.0), screen);
+        heatmap.record(Vec2::new(90.0, 95.0), screen);
+        heatmap.record(Vec2::new(250.0, 50.0), screen);
+        heatmap.record(Vec2::new(390.0, 190.0), screen);
+        heatmap.record(Vec2::new(400.0, 200.0), screen); // the far edge belongs to the last cell
+        heatmap.record(Vec2::new(f32::NAN, 10.0), screen); // ignored
+        heatmap.record(Vec2::new(10.0, 10.0), Vec2::ZERO); // no window size yet: ignored
+
+        let cell = |col: usize, row: usize| heatmap.counts[row * 4 + col];
+        assert_eq!(cell(0, 0), 2);
+        assert_eq!(cell(2, 0), 1);
+        assert_eq!(cell(3, 1), 2);
+        assert_eq!(heatmap.counts.iter().sum::<u32>(), 5);
+        assert_eq!(resources::Heatmap::new(0, 500).cols, 1);
+        assert_eq!(resources::Heatmap::new(0, 500).rows, resources::Heatmap::MAX_DIM);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(heatmap)
+            .add_systems(Update, publish_heatmap);
+        app.update();
+        assert_eq!(get_heatmap(), r#"{"cols":4,"rows":2,"counts":[2,0,1,0,0,0,0,2]}"#);
+    }
+
+    #[test]
+    fn test_catalog_summary_counts_species() {
+        assert_eq!(get_catalog_summary(), r#"{"total":0,"bySpecies":{}}"#, "empty before the catalog loads");
+
+        // A catalog with a second bird next to the test catalog's bird and bunny
+        let catalog = test_support::TEST_CATALOG_RON.replacen(
+            r#""bouncy_bunny": CritterData("#,
+            r#""hoppy_bird": CritterData(
+        name: "Hoppy",
+        species: Bird,
+        sprite: SpriteData(
+            path: "critters/hoppy_bird.png",
+            frame_layout: FrameLayout(image_size: (64, 64), frame_count: 1, frame_size: (64, 64), layout: Horizontal),
+            animations: {},
+        ),
+        stats: CritterStats(base_speed: 100.0, energy: 0.5, happiness_boost: 0.5),
+    ),
+    "bouncy_bunny": CritterData("#,
+            1,
+        );
+        let registry = resources::CritterRegistry::from_ron(&catalog, String::new()).unwrap();
+        let summary = registry.summary();
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.by_species.get("Bird"), Some(&2));
+        assert_eq!(summary.by_species.get("Bunny"), Some(&1));
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(registry)
+            .add_systems(Update, systems::catalog_summary_snapshot_system);
+        app.update();
+        assert_eq!(catalog_summary(), Some(summary));
+        assert_eq!(get_catalog_summary(), r#"{"total":3,"bySpecies":{"Bird":2,"Bunny":1}}"#);
+    }
+
+    #[test]
+    fn test_catch_updates_telemetry_counters() {
+        let mut game = test_support::TestGame::new();
+        game.app.add_systems(Update, publish_telemetry.after(systems::critter_interaction_system));
+
+        let bird = game.spawn("chirpy_bird");
+        game.tap(bird);
+        let counters = telemetry();
+        assert_eq!((counters.critters_spawned, counters.critters_caught, counters.critters_missed), (1, 1, 0));
+        assert!(get_telemetry().contains("\"crittersCaught\":1"), "{}", get_telemetry());
+
+        // A practice dummy spawns like any critter, but catching it isn't a catch
+        let dummy = game.spawn("bouncy_bunny");
+        game.app.world_mut().entity_mut(dummy).insert(components::Persistent);
+        game.tap(dummy);
+        assert!(game.critters().contains(&dummy), "practice dummy stays on the board");
+        let counters = telemetry();
+        assert_eq!((counters.critters_spawned, counters.critters_caught), (2, 1));
+
+        TELEMETRY_RESET_REQUESTED.store(true, std::sync::atomic::Ordering::SeqCst);
+        game.update();
+        assert_eq!(telemetry(), resources::Telemetry::default());
+        assert_eq!(*game.app.world().resource::<resources::Telemetry>(), resources::Telemetry::default());
+    }
+
+    #[test]
+    fn test_nearest_critter_query() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, systems::critter_positions_snapshot_system);
+        let critter = |name: &str| test_support::test_critter(name, components::CritterSpecies::Bunny);
+        app.update();
+        assert_eq!(nearest_critter_position(Vec2::ZERO), None);
+
+        app.world_mut().spawn((critter("bun"), Transform::from_xyz(-200.0, 0.0, 100.0)));
+        app.world_mut().spawn((critter("pup"), Transform::from_xyz(150.0, 80.0, 100.0)));
+        app.update();
+        assert_eq!(nearest_critter_position(Vec2::new(100.0, 50.0)), Some(("pup".to_string(), Vec2::new(150.0, 80.0))));
+        assert_eq!(nearest_critter_position(Vec2::new(-120.0, -40.0)), Some(("bun".to_string(), Vec2::new(-200.0, 0.0))));
+    }
+
+    #[test]
+    fn test_poisoned_queue_is_recovered_and_counted() {
+        static QUEUE: Mutex<VecDeque<u32>> = Mutex::new(VecDeque::new());
+        lock_queue(&QUEUE).push_back(1);
+
+        // A panic while holding the lock poisons it
+        let _ = std::thread::spawn(|| {
+            let mut queue = QUEUE.lock().unwrap();
+            queue.push_back(2);
+            panic!("system panicked mid-push");
+        }).join();
+        assert!(QUEUE.is_poisoned());
+
+        let before = QUEUE_LOCK_RECOVERIES.load(std::sync::atomic::Ordering::Relaxed);
+        lock_queue(&QUEUE).push_back(3);
+        assert_eq!(QUEUE_LOCK_RECOVERIES.load(std::sync::atomic::Ordering::Relaxed), before + 1);
+        assert!(!QUEUE.is_poisoned(), "recovery clears the poison so it's counted once");
+
+        // Nothing queued before or after the panic was dropped
+        assert_eq!(lock_queue(&QUEUE).drain(..).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(QUEUE_LOCK_RECOVERIES.load(std::sync::atomic::Ordering::Relaxed), before + 1);
+    }
+
+    #[test]
+    fn test_game_control_requests_reach_each_area() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(resources::CritterRegistry::from_ron(test_support::TEST_CATALOG_RON, String::new()).expect("test catalog parses"))
+            .init_resource::<resources::GameConfig>()
+            .init_resource::<resources::SpawnRotation>()
+            .init_resource::<resources::DwellConfig>()
+            .init_resource::<resources::AssistConfig>()
+            .init_resource::<resources::EscapeConfig>()
+            .init_resource::<resources::TutorialState>()
+            .init_resource::<resources::PracticeState>()
+            .init_resource::<resources::CritterSwitch>()
+            .init_resource::<resources::InteractionCancel>()
+            .init_resource::<game::GameState>()
+            .init_resource::<resources::FeedbackIntensity>()
+            .init_resource::<resources::DespawnStyleConfig>()
+            .init_resource::<effects::ExplosionConfig>()
+            .init_resource::<effects::ExplosionSoundConfig>()
+            .init_resource::<resources::SpawnSoundConfig>()
+            .init_resource::<events::MusicPolicy>()
+            .init_resource::<events::SharedSettings>()
+            .init_resource::<resources::CameraView>()
+            .init_resource::<camera::PostureRecognitionState>()
+            .init_resource::<resources::PixelPerfect>()
+            .init_resource::<resources::Heatmap>()
+            .init_resource::<effects::SwipeTrailConfig>()
+            .init_resource::<resources::UiConfig>()
+            .init_resource::<resources::ModeTransition>()
+            .init_resource::<resources::Countdown>()
+            .init_resource::<resources::SessionRecorder>()
+            .init_resource::<resources::GameRng>()
+            .add_event::<GameControlRequest>()
+            .add_event::<SpawnCritterEvent>()
+            .add_systems(Update, (apply_critter_controls, apply_feedback_controls, apply_view_controls, apply_session_controls));
+
+        for request in [
+            GameControlRequest::SetCritterCap { max_critters: 4, policy: resources::OverflowPolicy::default() },
+            GameControlRequest::SpawnPracticeCritter { critter_id: "bouncy_bunny".to_string() },
+            GameControlRequest::SpawnPracticeCritter { critter_id: "no_such_critter".to_string() }, // warned and dropped
+            GameControlRequest::SpawnCritter, // nothing selected yet: warned and dropped
+            GameControlRequest::SetMaxExplosions { max: 7 },
+            GameControlRequest::SetSwipeTrail { enabled: false },
+            GameControlRequest::SetStartingLives { lives: Some(2) },
+        ] {
+            app.world_mut().send_event(request);
+        }
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.resource::<resources::GameConfig>().max_critters, 4);
+        assert_eq!(world.resource::<resources::PracticeState>().requested.as_deref(), Some("bouncy_bunny"));
+        assert!(world.resource::<Events<SpawnCritterEvent>>().is_empty());
+        assert_eq!(world.resource::<effects::ExplosionConfig>().max_concurrent, 7);
+        assert!(!world.resource::<effects::SwipeTrailConfig>().enabled);
+        assert_eq!(world.resource::<game::GameState>().lives, Some(2));
+        assert_eq!(world.resource::<resources::GameConfig>().starting_lives, Some(2));
+    }
+
+    #[test]
+    fn test_build_info_has_timestamp_and_version() {
+        let info = build_info();
+        assert!(!info.timestamp.trim().is_empty());
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1,132 @@
+// Request tracking shared by the JS bridges (audio today, others as they migrate)
+// Every request that crosses into JS and expects an answer is registered here so
+// timeouts and outcomes are handled the same way across subsystems.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Lifecycle of a tracked request
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestStatus {
+    Pending,
+    Resolved,
+    Failed(String),
+    TimedOut,
+}
+
+impl RequestStatus {
+    pub fn is_pending(&self) -> bool {
+        matches!(self, RequestStatus::Pending)
+    }
+}
+
+/// One request waiting on (or answered by) the JS side
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedRequest {
+    pub kind: String, // "<subsystem>:<request>", e.g. "audio:play"
+    pub registered_at: f64, // ms, same clock as the `now` passed to the tracker
+    pub status: RequestStatus,
+}
+
+/// Outstanding bridge requests keyed by request id
+#[derive(Resource, Default, Debug)]
+pub struct RequestTracker {
+    requests: HashMap<String, TrackedRequest>,
+}
+
+impl RequestTracker {
+    /// Start tracking a request (re-registering an id restarts its clock)
+    pub fn register(&mut self, request_id: impl Into<String>, kind: impl Into<String>, now: f64) {
+        self.requests.insert(request_id.into(), TrackedRequest {
+            kind: kind.into(),
+            registered_at: now,
+            status: RequestStatus::Pending,
+        });
+    }
+
+    /// Record the outcome of a pending request; returns false for unknown or already settled ids
+    pub fn resolve(&mut self, request_id: &str, status: RequestStatus) -> bool {
+        match self.requests.get_mut(request_id) {
+            Some(tracked) if tracked.status.is_pending() => {
+                tracked.status = status;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Mark pending requests of a subsystem older than `timeout_ms` as timed out, returning their ids.
+    /// Settled requests past the timeout are forgotten on the next sweep so the map stays bounded.
+    pub fn reap_timed_out(&mut self, kind_prefix: &str, now: f64, timeout_ms: f64) -> Vec<String> {
+        let mut timed_out = Vec::new();
+        self.requests.retain(|id, tracked| {
+            if !tracked.kind.starts_with(kind_prefix) || now - tracked.registered_at <= timeout_ms {
+                return true;
+            }
+            if tracked.status.is_pending() {
+                tracked.status = RequestStatus::TimedOut;
+                timed_out.push(id.clone());
+                return true;
+            }
+            false
+        });
+        timed_out.sort();
+        timed_out
+    }
+
+    pub fn status(&self, request_id: &str) -> Option<&RequestStatus> {
+        self.requests.get(request_id).map(|tracked| &tracked.status)
+    }
+
+    /// Number of requests of a subsystem still waiting on an answer
+    pub fn pending_count(&self, kind_prefix: &str) -> usize {
+        self.requests
+            .values()
+            .filter(|tracked| tracked.kind.starts_with(kind_prefix) && tracked.status.is_pending())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_resolve() {
+        let mut tracker = RequestTracker::default();
+        tracker.register("a1", "audio:play", 1_000.0);
+        assert_eq!(tracker.pending_count("audio"), 1);
+        assert_eq!(tracker.pending_count("bluetooth"), 0);
+
+        assert!(tracker.resolve("a1", RequestStatus::Resolved));
+        assert_eq!(tracker.status("a1"), Some(&RequestStatus::Resolved));
+        assert_eq!(tracker.pending_count("audio"), 0);
+
+        // Settled and unknown ids are left alone
+        assert!(!tracker.resolve("a1", RequestStatus::Failed("late".to_string())));
+        assert!(!tracker.resolve("missing", RequestStatus::Resolved));
+        assert_eq!(tracker.status("a1"), Some(&RequestStatus::Resolved));
+    }
+
+    #[test]
+    fn test_reap_timed_out_requests() {
+        let mut tracker = RequestTracker::default();
+        tracker.register("stale", "audio:play", 1_000.0);
+        tracker.register("answered", "audio:stop", 1_000.0);
+        tracker.register("fresh", "audio:play", 40_000.0);
+        tracker.register("other", "bluetooth:connect", 1_000.0);
+        tracker.resolve("answered", RequestStatus::Resolved);
+
+        let reaped = tracker.reap_timed_out("audio", 45_000.0, 30_000.0);
+        assert_eq!(reaped, vec!["stale".to_string()]);
+        assert_eq!(tracker.status("stale"), Some(&RequestStatus::TimedOut));
+        assert!(tracker.status("answered").is_none(), "old settled entries are pruned");
+        assert_eq!(tracker.status("fresh"), Some(&RequestStatus::Pending));
+        assert_eq!(tracker.status("other"), Some(&RequestStatus::Pending), "other subsystems are untouched");
+
+        // A late answer can't revive it, and the next sweep forgets it
+        assert!(!tracker.resolve("stale", RequestStatus::Resolved));
+        assert!(tracker.reap_timed_out("audio", 46_000.0, 30_000.0).is_empty());
+        assert!(tracker.status("stale").is_none());
+    }
+}
@@ -1,6 +1,11 @@
+use bevy::image::ImageSampler;
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::components::{Critter, CritterSpecies};
+use crate::components::{AnimationLoopMode, Critter, CritterSpecies};
+use crate::game::InteractionType;
 use critter_keeper::{CritterCatalog, CritterConfig};
 
 /// Global game assets resource
@@ -28,6 +33,7 @@ pub struct AssetCollection {
 pub struct SelectedCritterAsset {
     pub handle: Option<Handle<Image>>,
     pub url: Option<String>,
+    pub critter_id: Option<String>,
 }
 
 /// Critter registry for managing different anthropomorphic game characters using critter-keeper
@@ -36,19 +42,151 @@ pub struct CritterRegistry {
     pub catalog: CritterCatalog,
     pub config: CritterConfig,
     pub unlocked_critters: Vec<String>, // Now using critter IDs instead of indices
+    pub animation_modes: HashMap<String, HashMap<String, AnimationLoopMode>>, // critter id -> animation -> mode, where the catalog sets one
+    pub tints: HashMap<String, Color>, // critter id -> sprite tint (catalog `tint:` or set_critter_tint())
+    pub curiosity: HashMap<String, f32>, // critter id -> catalog `stats: (curiosity: ..)`, where set
+}
+
+/// Display name of a catalog species ("Bird", "Bunny")
+pub fn species_name(species: &critter_keeper::CritterSpecies) -> &'static str {
+    match species {
+        critter_keeper::CritterSpecies::Bird => "Bird",
+        critter_keeper::CritterSpecies::Bunny => "Bunny",
+    }
+}
+
+/// Catalog overview for get_catalog_summary(), e.g. 12 critters: 5 Bird, 7 Bunny
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogSummary {
+    pub total: usize,
+    pub by_species: std::collections::BTreeMap<String, usize>,
+}
+
+impl CatalogSummary {
+    pub fn from_species<'a>(species: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut summary = Self::default();
+        for name in species {
+            summary.total += 1;
+            *summary.by_species.entry(name.to_string()).or_default() += 1;
+        }
+        summary
+    }
+}
+
+// Just enough of the catalog shape to pick up the optional fields the engine adds (a critter's `tint:`,
+// its `stats: (curiosity: ..)`, an animation's `mode:`), which critter-keeper's own types skip. Names match the catalog's so named
+// RON structs still parse.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "CritterCatalog")]
+struct CatalogExtrasDef {
+    critters: HashMap<String, CritterExtrasDef>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "CritterData")]
+struct CritterExtrasDef {
+    #[serde(deserialize_with = "present")]
+    tint: Option<[f32; 4]>,
+    stats: StatsExtrasDef,
+    sprite: SpriteExtrasDef,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "CritterStats")]
+struct StatsExtrasDef {
+    #[serde(deserialize_with = "present")]
+    curiosity: Option<f32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "SpriteData")]
+struct SpriteExtrasDef {
+    animations: HashMap<String, AnimationExtrasDef>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "Animation")]
+struct AnimationExtrasDef {
+    #[serde(deserialize_with = "present")]
+    mode: Option<AnimationLoopMode>,
+}
+
+// A field that is written at all is Some (RON would otherwise want `Some(..)` spelled out)
+fn present<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Engine-side catalog fields; critters and animations that don't set them are left out
+#[derive(Debug, Default)]
+pub struct CatalogExtras {
+    pub animation_modes: HashMap<String, HashMap<String, AnimationLoopMode>>,
+    pub tints: HashMap<String, Color>,
+    pub curiosity: HashMap<String, f32>,
+}
+
+pub fn parse_catalog_extras(catalog_ron: &str) -> Result<CatalogExtras, ron::error::SpannedError> {
+    let catalog: CatalogExtrasDef = ron::from_str(catalog_ron)?;
+    let mut extras = CatalogExtras::default();
+    for (id, critter) in catalog.critters {
+        if let Some([r, g, b, a]) = critter.tint {
+            extras.tints.insert(id.clone(), tint_color(r, g, b, a));
+        }
+        if let Some(curiosity) = critter.stats.curiosity {
+            extras.curiosity.insert(id.clone(), curiosity);
+        }
+        let modes = critter.sprite.animations.into_iter()
+            .filter_map(|(name, animation)| animation.mode.map(|mode| (name, mode)))
+            .collect();
+        extras.animation_modes.insert(id, modes);
+    }
+    Ok(extras)
+}
+
+/// RGBA tint with each channel clamped to 0-1 (a non-finite channel counts as 1, i.e. untinted)
+pub fn tint_color(r: f32, g: f32, b: f32, a: f32) -> Color {
+    let channel = |v: f32| if v.is_finite() { v.clamp(0.0, 1.0) } else { 1.0 };
+    Color::srgba(channel(r), channel(g), channel(b), channel(a))
 }
 
 impl CritterRegistry {
     pub fn from_ron(catalog_ron: &str, base_url: String) -> Result<Self, Box<dyn std::error::Error>> {
         let catalog: CritterCatalog = ron::from_str(catalog_ron)?;
+        let extras = parse_catalog_extras(catalog_ron)?;
         let config = CritterConfig::new(base_url, "critters/catalog.ron".to_string());
         
         Ok(Self {
             catalog,
             config,
             unlocked_critters: vec!["chirpy_bird".to_string()], // Bird unlocked by default
+            animation_modes: extras.animation_modes,
+            tints: extras.tints,
+            curiosity: extras.curiosity,
         })
     }
+
+    /// Critters without a catalog curiosity sit at the threshold: they never wander off to investigate
+    pub const DEFAULT_CURIOSITY: f32 = 0.5;
+
+    /// Personality curiosity (0-1) for a critter, from its catalog stats
+    pub fn curiosity(&self, critter_id: &str) -> f32 {
+        self.curiosity.get(critter_id).copied().unwrap_or(Self::DEFAULT_CURIOSITY)
+    }
+    
+    /// Critter count and per-species breakdown of the loaded catalog
+    pub fn summary(&self) -> CatalogSummary {
+        CatalogSummary::from_species(self.catalog.critters.values().map(|critter| species_name(&critter.species)))
+    }
+    
+    /// Sprite tint for a critter, if the catalog or the API gave it one
+    pub fn tint(&self, critter_id: &str) -> Option<Color> {
+        self.tints.get(critter_id).copied()
+    }
+    
+    /// Loop mode the catalog declares for one of a critter's animations, if any
+    pub fn animation_mode(&self, critter_id: &str, animation: &str) -> Option<AnimationLoopMode> {
+        self.animation_modes.get(critter_id)?.get(animation).copied()
+    }
     
     pub fn get_available_critters(&self) -> Vec<String> {
         self.catalog.critters.keys().cloned().collect()
@@ -57,15 +195,84 @@ impl CritterRegistry {
     pub fn is_unlocked(&self, critter_id: &str) -> bool {
         self.unlocked_critters.contains(&critter_id.to_string())
     }
+
+    /// Unlock a critter; returns false when it was already unlocked
+    pub fn unlock(&mut self, critter_id: &str) -> bool {
+        if self.is_unlocked(critter_id) {
+            return false;
+        }
+        self.unlocked_critters.push(critter_id.to_string());
+        true
+    }
+
+    /// Clamp every critter's stats into sane ranges, returning a warning per corrected value
+    pub fn validate_stats(&mut self) -> Vec<String> {
+        let mut ids: Vec<String> = self.catalog.critters.keys().cloned().collect();
+        ids.sort();
+        let mut warnings = Vec::new();
+        for id in ids {
+            let Some(critter) = self.catalog.critters.get_mut(&id) else { continue };
+            let stats = &mut critter.stats;
+            for (stat, value, range) in [
+                ("base_speed", &mut stats.base_speed, STAT_SPEED_RANGE),
+                ("energy", &mut stats.energy, STAT_UNIT_RANGE),
+                ("happiness_boost", &mut stats.happiness_boost, STAT_UNIT_RANGE),
+            ] {
+                let (clamped, warning) = clamp_stat(&id, stat, *value, range);
+                *value = clamped;
+                warnings.extend(warning);
+            }
+            if let Some(curiosity) = self.curiosity.get_mut(&id) {
+                let (clamped, warning) = clamp_stat(&id, "curiosity", *curiosity, STAT_UNIT_RANGE);
+                *curiosity = clamped;
+                warnings.extend(warning);
+            }
+        }
+        warnings
+    }
+}
+
+/// Allowed `base_speed` (px/s): slower is effectively immovable, faster looks like teleporting
+pub const STAT_SPEED_RANGE: (f32, f32) = (10.0, 600.0);
+/// Allowed range for the 0..1 stats (energy, happiness_boost, curiosity)
+pub const STAT_UNIT_RANGE: (f32, f32) = (0.0, 1.0);
+
+/// Clamp one catalog stat into `range`; non-finite values fall to the minimum. Returns a warning when changed.
+pub fn clamp_stat(critter_id: &str, stat: &str, value: f32, range: (f32, f32)) -> (f32, Option<String>) {
+    let (min, max) = range;
+    let clamped = if value.is_finite() { value.clamp(min, max) } else { min };
+    if clamped == value {
+        return (value, None);
+    }
+    let warning = format!("{}: {} {} out of range [{}, {}]; clamped to {}", critter_id, stat, value, min, max, clamped);
+    (clamped, Some(warning))
 }
 
 // No Default implementation! Must be initialized with real critter data using from_ron()
 // This forces proper error handling instead of masking missing data with fallbacks
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CritterSoundSet {
     pub entry: String,
-    pub success: String,
+    pub success: Vec<String>, // never empty; one is picked per catch
+    pub success_weights: Vec<f32>, // parallel to `success`; empty = equally likely
+}
+
+impl CritterSoundSet {
+    /// Success sound for one catch: a weighted pick from the game RNG when the critter has several
+    pub fn pick_success(&self, rng: &mut impl Rng) -> &str {
+        if self.success.len() <= 1 {
+            return self.success.first().map_or("", String::as_str);
+        }
+        let weighted = (self.success_weights.len() == self.success.len())
+            .then(|| rand::distributions::WeightedIndex::new(&self.success_weights).ok())
+            .flatten();
+        let index = match weighted {
+            Some(weights) => rand::distributions::Distribution::sample(&weights, rng),
+            None => rng.gen_range(0..self.success.len()),
+        };
+        &self.success[index]
+    }
 }
 
 #[derive(Resource, Default)]
@@ -77,8 +284,710 @@ pub struct CritterSounds {
 #[derive(Resource, Default)]
 pub struct AudioGate {
     pub enabled: bool,
+    pub muted: bool, // set_muted(); mirrors AudioManager::muted for catalog sounds
+}
+
+impl AudioGate {
+    /// Catalog sounds may play: unlocked by a gesture and not muted
+    pub fn can_play(&self) -> bool {
+        self.enabled && !self.muted
+    }
+}
+
+/// Accessibility assist: critters drift toward the last tap so young/disabled dogs catch them more easily
+#[derive(Resource, Debug, Clone)]
+pub struct AssistConfig {
+    pub magnet_strength: f32, // steering acceleration in px/s^2 (0.0 = off)
+    pub tap_memory_secs: f32, // how long a tap keeps attracting critters
+}
+
+impl Default for AssistConfig {
+    fn default() -> Self {
+        Self {
+            magnet_strength: 0.0,
+            tap_memory_secs: 2.0,
+        }
+    }
+}
+
+/// Most recent interaction position in world space, used by the magnet assist
+#[derive(Resource, Default, Debug, Clone)]
+pub struct RecentTap {
+    pub position: Option<Vec2>,
+    pub age_secs: f32,
+}
+
+/// Score display layout/branding supplied by the embedding app
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiConfig {
+    pub visible: bool,
+    pub left_px: f32,
+    pub top_px: f32,
+    pub font_size: f32,
+    pub color: String, // CSS-style hex, e.g. "#FFFFFF" or "#FFD700CC"
+}
+
+impl UiConfig {
+    /// Parsed text color, or an error naming the bad hex value
+    pub fn text_color(&self) -> Result<Color, String> {
+        Srgba::hex(&self.color)
+            .map(Color::from)
+            .map_err(|e| format!("Invalid UI color '{}': {}", self.color, e))
+    }
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            left_px: 0.0,
+            top_px: 0.0,
+            font_size: 40.0,
+            color: "#FFFFFF".to_string(),
+        }
+    }
 }
 
+/// Nearest-neighbour sampling for crisp pixel art, toggled separately for critters and the camera preview
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub struct PixelPerfect {
+    pub critters: bool,
+    pub camera_preview: bool,
+}
+
+impl PixelPerfect {
+    /// Sampler for an image: nearest when pixel-perfect, otherwise the app's default (linear)
+    pub fn sampler(enabled: bool) -> ImageSampler {
+        if enabled { ImageSampler::nearest() } else { ImageSampler::Default }
+    }
+}
+
+/// Session usage counters for product analytics, bumped inline by the systems that see each event
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Telemetry {
+    pub critters_spawned: u32,
+    pub critters_caught: u32, // practice dummies (`Persistent`) don't count, they can be caught over and over
+    pub critters_missed: u32, // catch gestures that hit no critter
+    pub sounds_played: u32,
+    pub explosions: u32,
+    pub lives: Option<u32>, // remaining lives, None when unlimited
+}
+
+/// Where on screen taps land, counted into a coarse grid for spawn-area and layout tuning (get_heatmap()).
+/// `counts` is row-major with row 0 at the top of the screen
+#[derive(Resource, Debug, Clone, PartialEq, Serialize)]
+pub struct Heatmap {
+    pub cols: u32,
+    pub rows: u32,
+    pub counts: Vec<u32>,
+}
+
+impl Default for Heatmap {
+    fn default() -> Self {
+        Self::new(12, 8)
+    }
+}
+
+impl Heatmap {
+    pub const MAX_DIM: u32 = 64;
+
+    /// Empty grid; each dimension is clamped to 1..=MAX_DIM
+    pub fn new(cols: u32, rows: u32) -> Self {
+        let cols = cols.clamp(1, Self::MAX_DIM);
+        let rows = rows.clamp(1, Self::MAX_DIM);
+        Self { cols, rows, counts: vec![0; (cols * rows) as usize] }
+    }
+
+    /// Count a tap at `screen_pos` (px from the top-left) on a screen of `screen_size`; edge taps land in edge cells
+    pub fn record(&mut self, screen_pos: Vec2, screen_size: Vec2) {
+        if !screen_pos.is_finite() || screen_size.x <= 0.0 || screen_size.y <= 0.0 {
+            return;
+        }
+        let cell = |pos: f32, size: f32, cells: u32| ((pos / size * cells as f32).floor().max(0.0) as u32).min(cells - 1);
+        let col = cell(screen_pos.x, screen_size.x, self.cols);
+        let row = cell(screen_pos.y, screen_size.y, self.rows);
+        self.counts[(row * self.cols + col) as usize] += 1;
+    }
+}
+
+/// How big catch feedback is: explosion particles, sound volume and vibration scale together.
+/// `Low` suits dogs that get overstimulated; `High` is the full effect
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedbackIntensity {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl FeedbackIntensity {
+    pub const ALL: [FeedbackIntensity; 3] = [FeedbackIntensity::Low, FeedbackIntensity::Medium, FeedbackIntensity::High];
+
+    pub fn parse(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.name() == name.to_ascii_lowercase())
+            .ok_or_else(|| format!("Unknown feedback intensity: '{}' (expected low, medium or high)", name))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeedbackIntensity::Low => "low",
+            FeedbackIntensity::Medium => "medium",
+            FeedbackIntensity::High => "high",
+        }
+    }
+
+    /// Multiplier on explosion particle counts
+    pub fn particle_scale(&self) -> f32 {
+        match self {
+            FeedbackIntensity::Low => 0.2,
+            FeedbackIntensity::Medium => 0.5,
+            FeedbackIntensity::High => 1.0,
+        }
+    }
+
+    /// Multiplier on catch and explosion sound volume
+    pub fn volume_scale(&self) -> f32 {
+        match self {
+            FeedbackIntensity::Low => 0.35,
+            FeedbackIntensity::Medium => 0.7,
+            FeedbackIntensity::High => 1.0,
+        }
+    }
+
+    /// Multiplier on vibration strength (collar Vibrate intensity; navigator.vibrate via get_feedback_intensity)
+    pub fn vibration_scale(&self) -> f32 {
+        match self {
+            FeedbackIntensity::Low => 0.25,
+            FeedbackIntensity::Medium => 0.6,
+            FeedbackIntensity::High => 1.0,
+        }
+    }
+
+    /// `base` particles at this intensity; never drops to zero so a catch is always visible
+    pub fn particle_count(&self, base: usize) -> usize {
+        ((base as f32 * self.particle_scale()).round() as usize).max(1)
+    }
+}
+
+/// How a caught critter leaves the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DespawnStyle {
+    Explode,
+    FadeOut, // gentle shrink + fade, no particles
+}
+
+impl DespawnStyle {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "explode" => Ok(DespawnStyle::Explode),
+            "fade_out" => Ok(DespawnStyle::FadeOut),
+            other => Err(format!("Unknown despawn style: '{}' (expected explode or fade_out)", other)),
+        }
+    }
+}
+
+/// Despawn style per feedback intensity; calm (`Low`) fades out by default
+#[derive(Resource, Debug, Clone)]
+pub struct DespawnStyleConfig {
+    pub per_intensity: [DespawnStyle; 3], // in FeedbackIntensity::ALL order
+}
+
+impl Default for DespawnStyleConfig {
+    fn default() -> Self {
+        Self { per_intensity: [DespawnStyle::FadeOut, DespawnStyle::Explode, DespawnStyle::Explode] }
+    }
+}
+
+impl DespawnStyleConfig {
+    fn index(intensity: FeedbackIntensity) -> usize {
+        FeedbackIntensity::ALL.iter().position(|level| *level == intensity).unwrap_or(2)
+    }
+
+    pub fn style_for(&self, intensity: FeedbackIntensity) -> DespawnStyle {
+        self.per_intensity[Self::index(intensity)]
+    }
+
+    pub fn set(&mut self, intensity: FeedbackIntensity, style: DespawnStyle) {
+        self.per_intensity[Self::index(intensity)] = style;
+    }
+}
+
+/// Dwell catching for gaze/slow-pointer input: resting the pointer on a critter this long catches it (0 = off)
+#[derive(Resource, Debug, Clone)]
+pub struct DwellConfig {
+    pub dwell_secs: f32,
+}
+
+impl Default for DwellConfig {
+    fn default() -> Self {
+        Self { dwell_secs: 1.0 }
+    }
+}
+
+/// Where the reported pointer is (world space) and how long it has rested on which critter
+#[derive(Resource, Debug, Default)]
+pub struct DwellTracker {
+    pub pointer: Option<Vec2>,
+    pub screen_pointer: Option<Vec2>, // the same report in raw screen px, for the recorder and heatmap
+    pub screen_size: Vec2,
+    pub target: Option<Entity>,
+    pub elapsed_secs: f32,
+    pub fired: bool, // already caught `target`; wait for the pointer to move on
+}
+
+/// Set by cancel_interaction() when a gesture is interrupted (the paw slid off the screen); the next frame
+/// abandons any pet or dwell in progress without scoring it
+#[derive(Resource, Debug, Default)]
+pub struct InteractionCancel {
+    pub requested: bool,
+}
+
+/// Rate limit for repeating per-frame warnings (e.g. a critter pointing at missing catalog data)
+#[derive(Resource, Debug, Default)]
+pub struct WarningThrottle {
+    last_logged: HashMap<String, f64>, // warning key -> elapsed secs when last logged
+    pub logged: u32,     // warnings let through
+    pub suppressed: u32, // repeats swallowed inside the window
+}
+
+impl WarningThrottle {
+    /// A given warning is repeated at most this often
+    pub const REPEAT_SECS: f64 = 10.0;
+
+    /// Whether the warning `key` should be logged now; records it if so
+    pub fn should_warn(&mut self, key: &str, now_secs: f64) -> bool {
+        match self.last_logged.get_mut(key) {
+            Some(last) if now_secs - *last < Self::REPEAT_SECS => {
+                self.suppressed += 1;
+                return false;
+            }
+            Some(last) => *last = now_secs,
+            None => {
+                self.last_logged.insert(key.to_string(), now_secs);
+            }
+        }
+        self.logged += 1;
+        true
+    }
+}
+
+/// Practice/calibration: a catalog id queued by spawn_practice_critter(), marked `Persistent` once it spawns
+#[derive(Resource, Debug, Default)]
+pub struct PracticeState {
+    pub requested: Option<String>,
+    pub awaiting_spawn: bool,
+}
+
+/// Mid-session critter change queued by switch_critter(); `replace_current` clears the stage for a prompt respawn
+#[derive(Resource, Debug, Default)]
+pub struct CritterSwitch {
+    pub requested: Option<String>,
+    pub replace_current: bool,
+}
+
+/// Timed arcade session started by start_timed_session(); counts down while playing and ends the game at zero
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct Countdown {
+    pub active: bool,
+    pub remaining_secs: f32,
+}
+
+impl Countdown {
+    pub fn start(&mut self, seconds: u32) {
+        self.active = true;
+        self.remaining_secs = seconds as f32;
+    }
+
+    /// Whole seconds shown on screen (rounded up, so "1" stays up until time actually runs out)
+    pub fn display_secs(&self) -> u32 {
+        self.remaining_secs.max(0.0).ceil() as u32
+    }
+}
+
+/// Onboarding: one stationary critter at screen center, auto-spawn paused until it's caught or dismissed
+#[derive(Resource, Debug, Default)]
+pub struct TutorialState {
+    pub requested: Option<String>, // catalog id queued by spawn_tutorial_critter()
+    pub awaiting_spawn: bool,
+    pub critter: Option<Entity>,
+    pub dismiss_requested: bool,
+}
+
+impl TutorialState {
+    pub fn is_active(&self) -> bool {
+        self.requested.is_some() || self.awaiting_spawn || self.critter.is_some()
+    }
+}
+
+/// UI blip (a registered audio id) played whenever a critter spawns, independent of its catalog entry sound
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SpawnSoundConfig {
+    pub enabled: bool, // off by default; the entry sound alone announces spawns
+    pub sound_id: Option<String>,
+}
+
+/// How uncaught critters escape: the timeout rule, and whether they run off-screen or just vanish
+#[derive(Resource, Debug, Clone)]
+pub struct EscapeConfig {
+    pub timeout_secs: f32, // critters left alone this long escape (0.0 = never)
+    pub animate: bool, // flee toward the nearest edge instead of disappearing on the spot
+    pub flee_acceleration: f32, // px/s^2 toward the edge
+    pub sound_id: Option<String>, // registered sound played as a critter escapes
+}
+
+impl Default for EscapeConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 0.0,
+            animate: true,
+            flee_acceleration: 900.0,
+            sound_id: None,
+        }
+    }
+}
+
+/// Fade to black and back played when the game mode changes
+#[derive(Resource, Debug, Clone)]
+pub struct ModeTransition {
+    pub duration_secs: f32, // whole fade out + in; 0.0 switches modes without a transition
+    pub timer: Option<Timer>, // running transition, if any
+}
+
+impl Default for ModeTransition {
+    fn default() -> Self {
+        Self { duration_secs: 0.4, timer: None }
+    }
+}
+
+impl ModeTransition {
+    /// Overlay alpha at or above which interactions are dropped
+    pub const BLOCKING_ALPHA: f32 = 0.5;
+
+    pub fn start(&mut self) {
+        self.timer = (self.duration_secs > 0.0).then(|| Timer::from_seconds(self.duration_secs, TimerMode::Once));
+    }
+
+    /// Jump straight to the end of a running transition
+    pub fn skip(&mut self) {
+        self.timer = None;
+    }
+
+    pub fn tick(&mut self, delta: std::time::Duration) {
+        if let Some(timer) = &mut self.timer {
+            if timer.tick(delta).finished() {
+                self.timer = None;
+            }
+        }
+    }
+
+    /// Overlay opacity: ramps to fully black at the midpoint, then back to clear
+    pub fn alpha(&self) -> f32 {
+        self.timer.as_ref().map_or(0.0, |timer| 1.0 - (2.0 * timer.fraction() - 1.0).abs())
+    }
+
+    pub fn blocks_input(&self) -> bool {
+        self.alpha() >= Self::BLOCKING_ALPHA
+    }
+}
+
+/// World-space camera pan/zoom set from JS (zoom > 1 magnifies)
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CameraView {
+    pub position: Vec2,
+    pub zoom: f32,
+}
+
+impl CameraView {
+    pub const MIN_ZOOM: f32 = 0.25;
+    pub const MAX_ZOOM: f32 = 4.0;
+
+    /// Clamp a requested zoom into the supported range (NaN falls back to 1:1)
+    pub fn clamp_zoom(zoom: f32) -> f32 {
+        if zoom.is_finite() { zoom.clamp(Self::MIN_ZOOM, Self::MAX_ZOOM) } else { 1.0 }
+    }
+
+    /// Screen pixels (origin top-left, y down) to world coordinates under this view
+    pub fn screen_to_world(&self, screen: Vec2, window_size: Vec2) -> Vec2 {
+        let from_center = Vec2::new(screen.x - window_size.x * 0.5, window_size.y * 0.5 - screen.y);
+        self.position + from_center / self.zoom
+    }
+
+    /// World position of a point given in pixels from the screen center (y up), e.g. screen-anchored overlays
+    pub fn screen_offset_to_world(&self, offset: Vec2) -> Vec2 {
+        self.position + offset / self.zoom
+    }
+}
+
+impl Default for CameraView {
+    fn default() -> Self {
+        Self { position: Vec2::ZERO, zoom: 1.0 }
+    }
+}
+
+/// Seeded RNG for gameplay randomness so sessions can be reproduced from a seed
+#[derive(Resource)]
+pub struct GameRng {
+    pub rng: StdRng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self { rng: StdRng::seed_from_u64(seed) }
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::from_seed(seed);
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        // Normal play gets a fresh seed; recordings capture it for replay
+        Self::from_seed(rand::thread_rng().gen())
+    }
+}
+
+/// Weighted random critter rotation: when enabled, each spawn picks which catalog critter appears
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SpawnRotation {
+    pub enabled: bool,
+    pub weights: HashMap<String, f32>, // critter id -> relative weight; unlisted critters weigh 1.0
+}
+
+impl SpawnRotation {
+    pub fn weight(&self, critter_id: &str) -> f32 {
+        self.weights.get(critter_id).copied().filter(|w| w.is_finite()).unwrap_or(1.0).max(0.0)
+    }
+
+    /// Weighted pick among `critter_ids` (sorted first so a seed always gives the same sequence);
+    /// None when there are no candidates or every weight is zero
+    pub fn pick<'a>(&self, critter_ids: impl IntoIterator<Item = &'a String>, rng: &mut impl Rng) -> Option<&'a String> {
+        let mut ids: Vec<&String> = critter_ids.into_iter().collect();
+        ids.sort();
+        let weights = ids.iter().map(|id| self.weight(id));
+        let index = rand::distributions::WeightedIndex::new(weights).ok()?;
+        Some(ids[rng.sample(index)])
+    }
+}
+
+/// A single recorded input, timestamped in seconds since recording started
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    Interaction { t: f64, interaction_type: String, x: f32, y: f32, dir_x: f32, dir_y: f32 },
+    Spawn { t: f64, x: f32, y: f32 },
+}
+
+impl RecordedEvent {
+    pub fn time(&self) -> f64 {
+        match self {
+            RecordedEvent::Interaction { t, .. } | RecordedEvent::Spawn { t, .. } => *t,
+        }
+    }
+}
+
+/// Exportable session recording (seed + ordered inputs)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecording {
+    pub seed: u64,
+    pub events: Vec<RecordedEvent>,
+    /// Real-time length (ns) of every frame after recording started; playback steps the clock through the same
+    /// frames so movement and per-frame randomness line up with the original session
+    #[serde(default)]
+    pub frame_nanos: Vec<u64>,
+}
+
+/// One live critter in a debug scene snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CritterSnapshot {
+    pub critter_id: String, // catalog id (falls back to the display name for critters without one)
+    pub name: String,
+    pub species: CritterSpecies,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub max_speed: f32,
+    pub energy: f32,
+    pub happiness: f32,
+    pub playfulness: f32,
+    pub curiosity: f32,
+    pub obedience: f32,
+    pub current: bool, // the critter GameState tracks as current
+}
+
+/// Gameplay tuning captured alongside a scene (the GameConfig knobs JS can change)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    pub time_scale: f32,
+    pub spawn_interval_secs: f32,
+    pub spawn_jitter: f32,
+    pub spawn_in_secs: f32,
+    pub interaction_sensitivity: f32,
+    pub min_swipe_px: f32,
+    pub separation_strength: f32,
+    pub min_alive_ms: f32,
+}
+
+impl ConfigSnapshot {
+    pub fn capture(config: &GameConfig) -> Self {
+        Self {
+            time_scale: config.time_scale,
+            spawn_interval_secs: config.spawn_interval_secs,
+            spawn_jitter: config.spawn_jitter,
+            spawn_in_secs: config.spawn_in_secs,
+            interaction_sensitivity: config.interaction_sensitivity,
+            min_swipe_px: config.min_swipe_px,
+            separation_strength: config.separation_strength,
+            min_alive_ms: config.min_alive_ms,
+        }
+    }
+
+    pub fn apply(&self, config: &mut GameConfig) {
+        config.time_scale = GameConfig::clamp_time_scale(self.time_scale);
+        config.spawn_interval_secs = self.spawn_interval_secs;
+        config.spawn_jitter = self.spawn_jitter.clamp(0.0, GameConfig::MAX_SPAWN_JITTER);
+        config.spawn_in_secs = self.spawn_in_secs.max(0.0);
+        config.interaction_sensitivity = self.interaction_sensitivity;
+        config.min_swipe_px = self.min_swipe_px;
+        config.separation_strength = self.separation_strength.max(0.0);
+        config.min_alive_ms = self.min_alive_ms.max(0.0);
+    }
+}
+
+/// Full debug snapshot of a scene (export_state / import_state), heavier than a progress save
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneSnapshot {
+    pub version: u32,
+    pub score: u32,
+    pub level: u32,
+    pub mode: String,
+    pub selected_critter_id: Option<String>,
+    pub config: ConfigSnapshot,
+    pub critters: Vec<CritterSnapshot>,
+}
+
+impl SceneSnapshot {
+    pub const VERSION: u32 = 1;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecorderMode {
+    #[default]
+    Idle,
+    Recording,
+    Playback,
+}
+
+/// Records interactions/spawns for bug reports and replays them deterministically
+#[derive(Resource, Default, Debug)]
+pub struct SessionRecorder {
+    pub mode: RecorderMode,
+    pub recording: SessionRecording,
+    started_at: f64,
+    cursor: usize,
+    frame_cursor: usize,
+    clock_armed: bool, // the frame recording started in has been skipped
+}
+
+impl SessionRecorder {
+    pub fn start_recording(&mut self, seed: u64, now: f64) {
+        self.mode = RecorderMode::Recording;
+        self.recording = SessionRecording { seed, ..Default::default() };
+        self.started_at = now;
+        self.cursor = 0;
+        self.clock_armed = false;
+    }
+
+    /// Stop recording or playback, returning the captured session
+    pub fn stop(&mut self) -> SessionRecording {
+        self.mode = RecorderMode::Idle;
+        self.recording.clone()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.mode == RecorderMode::Recording
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.mode == RecorderMode::Playback
+    }
+
+    /// Seconds elapsed since recording/playback started
+    pub fn elapsed(&self, now: f64) -> f64 {
+        (now - self.started_at).max(0.0)
+    }
+
+    pub fn record_interaction(&mut self, now: f64, interaction: &InteractionType, x: f32, y: f32) {
+        if !self.is_recording() { return; }
+        let t = self.elapsed(now);
+        let direction = interaction.direction();
+        self.recording.events.push(RecordedEvent::Interaction {
+            t,
+            interaction_type: interaction.name().to_string(),
+            x,
+            y,
+            dir_x: direction.x,
+            dir_y: direction.y,
+        });
+    }
+
+    pub fn record_spawn(&mut self, now: f64, position: Vec2) {
+        if !self.is_recording() { return; }
+        let t = self.elapsed(now);
+        self.recording.events.push(RecordedEvent::Spawn { t, x: position.x, y: position.y });
+    }
+
+    pub fn start_playback(&mut self, recording: SessionRecording, now: f64) {
+        self.mode = RecorderMode::Playback;
+        self.recording = recording;
+        self.started_at = now;
+        self.cursor = 0;
+        self.frame_cursor = 0;
+    }
+
+    /// Note a frame's real-time length while recording; the frame recording started in already ran and is skipped
+    pub fn record_frame(&mut self, delta: std::time::Duration) {
+        if !self.is_recording() { return; }
+        if std::mem::replace(&mut self.clock_armed, true) {
+            self.recording.frame_nanos.push(delta.as_nanos() as u64);
+        }
+    }
+
+    /// Length of the next recorded frame during playback, until the recording runs out of frames
+    pub fn next_frame(&mut self) -> Option<std::time::Duration> {
+        if !self.is_playing() { return None; }
+        let nanos = *self.recording.frame_nanos.get(self.frame_cursor)?;
+        self.frame_cursor += 1;
+        Some(std::time::Duration::from_nanos(nanos))
+    }
+
+    /// Pop every recorded event whose timestamp has been reached
+    pub fn take_due(&mut self, now: f64) -> Vec<RecordedEvent> {
+        if !self.is_playing() { return Vec::new(); }
+        // Recorded and replayed clocks start at different times, so the same frame can differ by float rounding
+        let elapsed = self.elapsed(now) + 1e-6;
+        let mut due = Vec::new();
+        while let Some(event) = self.recording.events.get(self.cursor) {
+            if event.time() > elapsed { break; }
+            due.push(event.clone());
+            self.cursor += 1;
+        }
+        due
+    }
+
+    /// Every recorded event has fired and every recorded frame has been stepped through
+    pub fn playback_finished(&self) -> bool {
+        self.is_playing()
+            && self.cursor >= self.recording.events.len()
+            && self.frame_cursor >= self.recording.frame_nanos.len()
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct CritterTemplate {
@@ -105,14 +1014,81 @@ pub struct TrainingProgress {
     pub training_streak: u32,
 }
 
+/// What a spawn does once the live critter cap is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    #[default]
+    Block, // drop the spawn
+    RecycleOldest, // despawn the oldest live critter to make room
+}
+
+impl OverflowPolicy {
+    /// Parse a policy name from JS ("block", "recycle_oldest")
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "block" => Ok(OverflowPolicy::Block),
+            "recycle_oldest" => Ok(OverflowPolicy::RecycleOldest),
+            other => Err(format!("Unknown overflow policy: '{}' (expected block or recycle_oldest)", other)),
+        }
+    }
+}
+
 /// Game configuration
 #[derive(Resource)]
 pub struct GameConfig {
     pub screen_bounds: Vec2,
     pub pet_spawn_bounds: Vec2,
-    pub interaction_sensitivity: f32,
+    pub interaction_sensitivity: f32, // >1.0 = shorter swipes register and push harder
+    pub min_swipe_px: f32, // swipes shorter than this (at sensitivity 1.0) are treated as taps
     pub audio_enabled: bool,
     pub vibration_enabled: bool,
+    pub time_scale: f32, // 1.0 = normal, 0.5 = slow-mo, 2.0 = fast-forward, 0.0 = soft pause
+    pub spawn_interval_secs: f32,
+    pub auto_spawn: bool, // false = critters only appear when the frontend spawns them
+    pub spawn_jitter: f32, // ± fraction of the spawn interval (0.0 = metronomic, deterministic)
+    pub spawn_in_secs: f32, // fade/scale-in duration for new critters (0.0 = pop in instantly)
+    pub interactable_during_spawn_in: bool,
+    pub entry_sound_after_spawn_in: bool, // hold the catalog entry sound until the spawn-in finishes (false = play at spawn)
+    pub rescale_on_resize: bool, // move live critters proportionally when the screen bounds change
+    pub keep_velocity_on_resize: bool, // with rescale: keep velocities as-is instead of scaling them per axis
+    pub min_alive_ms: f32, // catches on critters younger than this are dropped (0.0 = catchable immediately)
+    pub separation_strength: f32, // px/s^2 push between overlapping critters (0.0 = off)
+    pub curiosity_strength: f32, // px/s^2 pull on a fully curious critter toward the latest tap (0.0 = off, the default)
+    pub min_separation: f32, // critters closer than this are nudged apart
+    pub max_critters: usize, // live (uncaught) critters allowed at once
+    pub overflow_policy: OverflowPolicy,
+    pub offscreen_timeout_secs: f32, // critters stuck beyond the screen this long are cleaned up (0.0 = never)
+    pub catalog_timeout_secs: f32, // whole catalog load (all fetches and retries) fails after this long (0.0 = wait forever)
+    pub max_frame_delta_secs: f32, // longest frame step the game clock advances by; stalls beyond it are dropped
+    pub starting_lives: Option<u32>, // escapes allowed per game before game over (None = unlimited)
+    pub input_offset: Vec2, // px added to reported pointer positions (corrects CSS border/padding around the canvas)
+}
+
+impl GameConfig {
+    pub const MIN_TIME_SCALE: f32 = 0.0;
+    pub const MAX_TIME_SCALE: f32 = 4.0;
+    pub const MAX_SPAWN_JITTER: f32 = 0.9;
+    pub const MIN_FRAME_DELTA_CLAMP: f32 = 0.001;
+
+    /// Clamp a requested time scale into the supported range (NaN falls back to normal speed)
+    pub fn clamp_time_scale(scale: f32) -> f32 {
+        if scale.is_finite() { scale.clamp(Self::MIN_TIME_SCALE, Self::MAX_TIME_SCALE) } else { 1.0 }
+    }
+
+    /// Clamp a requested max frame delta to something the virtual clock accepts (NaN falls back to 1/15s)
+    pub fn clamp_max_frame_delta(secs: f32) -> f32 {
+        if secs.is_finite() { secs.max(Self::MIN_FRAME_DELTA_CLAMP) } else { 1.0 / 15.0 }
+    }
+
+    /// A reported pointer position corrected by the calibration offset, ready for screen-to-world conversion
+    pub fn calibrated_screen_pos(&self, x: f32, y: f32) -> Vec2 {
+        Vec2::new(x, y) + self.input_offset
+    }
+
+    /// Whether a spawn can go ahead with `live` critters out (recycling always makes room)
+    pub fn has_spawn_room(&self, live: usize) -> bool {
+        live < self.max_critters || self.overflow_policy == OverflowPolicy::RecycleOldest
+    }
 }
 
 impl Default for GameConfig {
@@ -121,8 +1097,29 @@ impl Default for GameConfig {
             screen_bounds: Vec2::new(1200.0, 800.0), // Larger screen bounds
             pet_spawn_bounds: Vec2::new(500.0, 350.0), // Larger spawn area
             interaction_sensitivity: 1.0,
+            min_swipe_px: 12.0,
             audio_enabled: true,
             vibration_enabled: true,
+            time_scale: 1.0,
+            spawn_interval_secs: 3.0,
+            auto_spawn: true,
+            spawn_jitter: 0.25,
+            spawn_in_secs: 0.35,
+            interactable_during_spawn_in: false,
+            entry_sound_after_spawn_in: false,
+            rescale_on_resize: false,
+            keep_velocity_on_resize: false,
+            min_alive_ms: 0.0,
+            separation_strength: 0.0, // single-critter mode needs no separation
+            curiosity_strength: 0.0, // opt-in, like the magnet assist: it changes how every curious critter roams
+            min_separation: 120.0,
+            max_critters: 1,
+            overflow_policy: OverflowPolicy::Block,
+            offscreen_timeout_secs: 3.0,
+            catalog_timeout_secs: 30.0,
+            max_frame_delta_secs: 1.0 / 15.0, // a GC pause or throttled tab moves critters at most 1/15s worth
+            starting_lives: None,
+            input_offset: Vec2::ZERO,
         }
     }
 }
@@ -0,0 +1,205 @@
+// Versioned player save data (progress, high scores, achievements) kept by the frontend in localStorage.
+// Every structure carries a `schemaVersion`; `migrate_save` upgrades older blobs to the current schema
+// so a future format change never reads (or overwrites) a save it doesn't understand.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Schema written by this build. v1 was the unversioned `{ highScore, currentLevel, lastPlayed }` stats blob.
+pub const SAVE_SCHEMA_VERSION: u32 = 2;
+
+fn current_version() -> u32 {
+    SAVE_SCHEMA_VERSION
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ProgressSave {
+    pub schema_version: u32,
+    pub current_level: u32,
+    pub last_played: Option<String>, // ISO timestamp from the frontend
+}
+
+impl Default for ProgressSave {
+    fn default() -> Self {
+        Self { schema_version: current_version(), current_level: 1, last_played: None }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HighScoreSave {
+    pub schema_version: u32,
+    pub best: u32,
+    pub achieved_at: Option<String>,
+}
+
+impl Default for HighScoreSave {
+    fn default() -> Self {
+        Self { schema_version: current_version(), best: 0, achieved_at: None }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AchievementSave {
+    pub schema_version: u32,
+    pub unlocked: Vec<String>,
+}
+
+impl Default for AchievementSave {
+    fn default() -> Self {
+        Self { schema_version: current_version(), unlocked: Vec::new() }
+    }
+}
+
+/// Everything persisted for a player, as stored under one localStorage key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SaveData {
+    pub schema_version: u32,
+    pub progress: ProgressSave,
+    pub high_scores: HighScoreSave,
+    pub achievements: AchievementSave,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            schema_version: current_version(),
+            progress: ProgressSave::default(),
+            high_scores: HighScoreSave::default(),
+            achievements: AchievementSave::default(),
+        }
+    }
+}
+
+/// Result of reading a stored blob: the upgraded data plus what had to be dropped on the way
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigratedSave {
+    pub data: SaveData,
+    pub from_version: u32,
+    pub discarded: Vec<&'static str>, // sections that were unreadable and reset to defaults
+}
+
+/// Parse a stored save of any known schema version and upgrade it to `SAVE_SCHEMA_VERSION`.
+/// Unreadable sections fall back to defaults; saves from a newer build are refused so they aren't clobbered.
+pub fn migrate_save(json: &str) -> Result<MigratedSave, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| format!("Save is not valid JSON: {}", e))?;
+    let Value::Object(ref fields) = value else {
+        return Err("Save is not a JSON object".to_string());
+    };
+    // The v1 stats blob predates version tags
+    let from_version = match fields.get("schemaVersion") {
+        None => 1,
+        Some(v) => v.as_u64().and_then(|v| u32::try_from(v).ok()).ok_or_else(|| format!("Invalid schemaVersion: {}", v))?,
+    };
+    match from_version {
+        1 => Ok(MigratedSave { data: migrate_v1(fields), from_version, discarded: Vec::new() }),
+        2 => {
+            let mut discarded = Vec::new();
+            let data = SaveData {
+                schema_version: SAVE_SCHEMA_VERSION,
+                progress: section(fields, "progress", &mut discarded),
+                high_scores: section(fields, "highScores", &mut discarded),
+                achievements: section(fields, "achievements", &mut discarded),
+            };
+            Ok(MigratedSave { data, from_version, discarded })
+        }
+        v if v > SAVE_SCHEMA_VERSION => Err(format!("Save schema {} is newer than supported ({})", v, SAVE_SCHEMA_VERSION)),
+        v => Err(format!("Unknown save schema {}", v)),
+    }
+}
+
+/// v1 → v2: the flat stats become progress + high score; there were no achievements yet
+fn migrate_v1(fields: &serde_json::Map<String, Value>) -> SaveData {
+    let number = |key: &str| fields.get(key).and_then(Value::as_u64).and_then(|v| u32::try_from(v).ok());
+    let last_played = fields.get("lastPlayed").and_then(Value::as_str).map(str::to_string);
+    SaveData {
+        progress: ProgressSave {
+            current_level: number("currentLevel").unwrap_or(1).max(1),
+            last_played,
+            ..Default::default()
+        },
+        high_scores: HighScoreSave { best: number("highScore").unwrap_or(0), ..Default::default() },
+        ..SaveData::default()
+    }
+}
+
+/// Read one section, resetting it if it's malformed or tagged with a schema we can't read
+fn section<T>(fields: &serde_json::Map<String, Value>, key: &'static str, discarded: &mut Vec<&'static str>) -> T
+where
+    T: Default + for<'de> Deserialize<'de>,
+{
+    let Some(raw) = fields.get(key) else { return T::default() };
+    let too_new = raw.get("schemaVersion").and_then(Value::as_u64).is_some_and(|v| v > SAVE_SCHEMA_VERSION as u64);
+    match serde_json::from_value::<T>(raw.clone()) {
+        Ok(parsed) if !too_new => parsed,
+        _ => {
+            discarded.push(key);
+            T::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_v1_stats_blob_migrates_to_current_schema() {
+        let v1 = r#"{"highScore": 420, "currentLevel": 3, "lastPlayed": "2026-01-02T03:04:05.000Z"}"#;
+        let migrated = migrate_save(v1).expect("v1 blob migrates");
+
+        assert_eq!(migrated.from_version, 1);
+        assert!(migrated.discarded.is_empty());
+        let data = migrated.data;
+        assert_eq!(data.schema_version, SAVE_SCHEMA_VERSION);
+        assert_eq!(data.progress.schema_version, SAVE_SCHEMA_VERSION);
+        assert_eq!(data.progress.current_level, 3);
+        assert_eq!(data.progress.last_played.as_deref(), Some("2026-01-02T03:04:05.000Z"));
+        assert_eq!(data.high_scores.best, 420);
+        // Fields v1 never had come in as defaults
+        assert_eq!(data.high_scores.achieved_at, None);
+        assert_eq!(data.achievements, AchievementSave::default());
+
+        // Written back out it reads as a current save, unchanged
+        let json = serde_json::to_string(&data).unwrap();
+        assert!(json.contains("\"schemaVersion\":2"));
+        let reread = migrate_save(&json).unwrap();
+        assert_eq!(reread.from_version, SAVE_SCHEMA_VERSION);
+        assert_eq!(reread.data, data);
+    }
+
+    #[test]
+    fn test_v1_blob_with_garbage_values_uses_defaults() {
+        let data = migrate_save(r#"{"highScore": "lots", "currentLevel": 0}"#).unwrap().data;
+        assert_eq!(data.high_scores.best, 0);
+        assert_eq!(data.progress.current_level, 1);
+    }
+
+    #[test]
+    fn test_incompatible_sections_are_discarded_not_fatal() {
+        let blob = r#"{
+            "schemaVersion": 2,
+            "progress": {"schemaVersion": 2, "currentLevel": 5},
+            "highScores": {"schemaVersion": 2, "best": "not a number"},
+            "achievements": {"schemaVersion": 9, "unlocked": ["first_catch"]}
+        }"#;
+        let migrated = migrate_save(blob).unwrap();
+        assert_eq!(migrated.discarded, vec!["highScores", "achievements"]);
+        assert_eq!(migrated.data.progress.current_level, 5);
+        // Missing fields inside a readable section are filled in
+        assert_eq!(migrated.data.progress.last_played, None);
+        assert_eq!(migrated.data.high_scores, HighScoreSave::default());
+        assert_eq!(migrated.data.achievements, AchievementSave::default());
+    }
+
+    #[test]
+    fn test_newer_or_broken_saves_are_refused() {
+        assert!(migrate_save(r#"{"schemaVersion": 3}"#).unwrap_err().contains("newer"));
+        assert!(migrate_save(r#"{"schemaVersion": 0}"#).is_err());
+        assert!(migrate_save("[1, 2]").is_err());
+        assert!(migrate_save("not json").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+// Headless game for native tests: GamePlugin's resources and events plus the spawn/catch/score systems,
+// without the startup systems that need a browser (UI fonts, catalog fetch) or a window. A small catalog is
+// seeded in memory so critters spawn from real catalog entries.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+use crate::components::{Critter, CritterMovement, CritterPersonality, CritterSpecies};
+use crate::effects::CritterExplodeEvent;
+use crate::game::{register_game_state, CritterInteractionEvent, GameState, InteractionType, SpawnCritterEvent};
+use crate::resources::CritterRegistry;
+use crate::systems::*;
+
+/// Game time each `update()` advances
+pub const FRAME: Duration = Duration::from_millis(100);
+
+static INTERACTION_QUEUE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Hold while a test pushes through the process-wide interaction queue, so parallel tests don't take each other's taps
+pub fn lock_interaction_queue() -> std::sync::MutexGuard<'static, ()> {
+    INTERACTION_QUEUE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Two critters: a curious bird with a one-shot "caught" reaction and a bunny that pops straight away
+pub const TEST_CATALOG_RON: &str = r#"CritterCatalog(critters: {
+    "chirpy_bird": CritterData(
+        name: "Chirpy",
+        species: Bird,
+        sprite: SpriteData(
+            path: "critters/chirpy_bird.png",
+            frame_layout: FrameLayout(image_size: (256, 128), frame_count: 8, frame_size: (64, 64), layout: Grid(cols: 4, rows: 2)),
+            animations: {
+                "idle": Animation(frames: [0, 1, 2, 3], fps: 8.0),
+                "caught": Animation(frames: [4, 5], fps: 10.0),
+            },
+        ),
+        stats: CritterStats(base_speed: 120.0, energy: 0.8, happiness_boost: 0.5, curiosity: 0.9),
+    ),
+    "bouncy_bunny": CritterData(
+        name: "Bouncy",
+        species: Bunny,
+        sprite: SpriteData(
+            path: "critters/bouncy_bunny.png",
+            frame_layout: FrameLayout(image_size: (128, 64), frame_count: 2, frame_size: (64, 64), layout: Horizontal),
+            animations: {
+                "idle": Animation(frames: [0, 1], fps: 4.0),
+            },
+        ),
+        stats: CritterStats(base_speed: 90.0, energy: 0.6, happiness_boost: 0.7),
+    ),
+})"#;
+
+/// A bare critter for tests that place entities by hand instead of spawning from the catalog
+pub fn test_critter(name: &str, species: CritterSpecies) -> Critter {
+    Critter {
+        name: name.to_string(),
+        species,
+        personality: CritterPersonality { playfulness: 0.5, curiosity: 0.5, obedience: 0.5 },
+        energy: 1.0,
+        happiness: 1.0,
+    }
+}
+
+pub struct TestGame {
+    pub app: App,
+}
+
+impl TestGame {
+    /// A game in Playing mode with the test catalog loaded; the clock is already running
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .insert_resource(CritterRegistry::from_ron(TEST_CATALOG_RON, String::new()).expect("test catalog parses"))
+            .add_event::<CritterExplodeEvent>();
+        register_game_state(&mut app)
+            .add_systems(Update, (
+                (cancel_interaction_system, dwell_system).chain(),
+                critter_spawning_system,
+                critter_interaction_system,
+                petting_system,
+                (spawn_in_system, caught_reaction_system, fade_out_system),
+                game_state_system,
+            ).chain());
+        app.world_mut().resource_mut::<GameState>().set_mode(crate::game::GameMode::Playing);
+        app.update(); // starts the clock
+        Self { app }
+    }
+
+    /// Run one frame
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    pub fn advance(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    /// Select a catalog critter and spawn it through the spawning system; returns the new critter
+    pub fn spawn(&mut self, critter_id: &str) -> Entity {
+        self.app.world_mut().resource_mut::<GameState>().selected_critter_id = Some(critter_id.to_string());
+        self.app.world_mut().send_event(SpawnCritterEvent { position: Vec2::ZERO });
+        self.update();
+        self.game_state().current_critter_id.unwrap_or_else(|| panic!("{} did not spawn", critter_id))
+    }
+
+    /// Deliver an interaction on a critter (at its position) and run the frame that handles it
+    pub fn interact(&mut self, critter: Entity, interaction_type: InteractionType) {
+        let position = self.app.world().get::<Transform>(critter).map_or(Vec2::ZERO, |t| t.translation.xy());
+        self.app.world_mut().send_event(CritterInteractionEvent { critter_entity: critter, interaction_type, position });
+        self.update();
+    }
+
+    pub fn tap(&mut self, critter: Entity) {
+        self.interact(critter, InteractionType::Tap);
+    }
+
+    /// Take pointer input the way the page delivers it: screen px through the interaction queue, resolved
+    /// against a window and camera (see `lock_interaction_queue`)
+    pub fn with_pointer_input(mut self) -> Self {
+        self.app
+            .add_event::<crate::effects::SwipeTrailEvent>()
+            .add_systems(Update, crate::process_interaction_queue.before(critter_interaction_system));
+        self.app.world_mut().spawn(Window::default());
+        self.app.world_mut().spawn((Camera::default(), GlobalTransform::default()));
+        self
+    }
+
+    /// Screen px (top-left origin, y down) showing `world` under the default camera view
+    pub fn screen_pos(&mut self, world: Vec2) -> Vec2 {
+        let window = self.app.world_mut().query::<&Window>().single(self.app.world()).expect("with_pointer_input window");
+        Vec2::new(world.x + window.width() * 0.5, window.height() * 0.5 - world.y)
+    }
+
+    pub fn game_state(&self) -> &GameState {
+        self.app.world().resource::<GameState>()
+    }
+
+    /// Critters still on the board (including ones mid caught-reaction)
+    pub fn critters(&mut self) -> Vec<Entity> {
+        self.app.world_mut().query_filtered::<Entity, With<Critter>>().iter(self.app.world()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_harness_spawns_and_catches_a_critter() {
+        let mut game = TestGame::new();
+
+        let bunny = game.spawn("bouncy_bunny");
+        assert_eq!(game.critters(), [bunny]);
+        let critter = game.app.world().get::<Critter>(bunny).unwrap();
+        assert_eq!(critter.name, "Bouncy");
+        assert_eq!(game.app.world().get::<CritterMovement>(bunny).unwrap().max_speed, 90.0);
+
+        // No caught animation: the tap pops it straight away
+        game.tap(bunny);
+        assert!(game.critters().is_empty());
+        assert_eq!(game.game_state().score, 50);
+        assert_eq!(game.game_state().current_critter_id, None);
+
+        // The bird plays its 0.2s caught reaction before it's gone
+        let bird = game.spawn("chirpy_bird");
+        game.tap(bird);
+        assert_eq!(game.critters(), [bird]);
+        assert_eq!(game.game_state().score, 100);
+        game.advance(3);
+        assert!(game.critters().is_empty());
+    }
+}
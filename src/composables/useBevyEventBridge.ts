@@ -6,7 +6,7 @@ import { ref, onMounted, onUnmounted } from 'vue'
 
 // TypeScript types matching the Rust events (manually synced)
 export interface BevyToJsEvent {
-  type: 'PlayAudio' | 'BluetoothScan' | 'TestEvent' | 'CameraStart' | 'CameraStop'
+  type: 'PlayAudio' | 'BluetoothScan' | 'TestEvent' | 'CameraStart' | 'CameraStop' | 'AssetLoadFailed' | 'EngineError' | 'ScoreChanged' | 'LifeLost' | 'TimeUp'
   request_id?: string
   sound_id?: string
   volume?: number
@@ -15,6 +15,20 @@ export interface BevyToJsEvent {
   // Camera options
   width?: number
   height?: number
+  // Asset failure details
+  url?: string
+  error?: string
+  critter_id?: string | null
+  // Engine error details
+  code?: string
+  // Score updates
+  delta?: number
+  total?: number
+  level?: number
+  // Lives
+  lives_left?: number
+  // Timed session
+  final_score?: number
 }
 
 export interface JsToBevyEvent {
@@ -29,6 +43,9 @@ export interface JsToBevyEvent {
     music_enabled: boolean
     bgm_volume: number
     sfx_volume: number
+    ui_volume?: number
+    critter_volume?: number
+    ambient_volume?: number
   }
 }
 
@@ -51,6 +68,11 @@ export class BevyEventBridge {
     this.eventHandlers.set('TestEvent', this.handleTestEvent.bind(this))
     this.eventHandlers.set('CameraStart', this.handleCameraStart.bind(this))
     this.eventHandlers.set('CameraStop', this.handleCameraStop.bind(this))
+    this.eventHandlers.set('AssetLoadFailed', this.handleAssetLoadFailed.bind(this))
+    this.eventHandlers.set('EngineError', this.handleEngineError.bind(this))
+    this.eventHandlers.set('ScoreChanged', this.handleScoreChanged.bind(this))
+    this.eventHandlers.set('LifeLost', this.handleLifeLost.bind(this))
+    this.eventHandlers.set('TimeUp', this.handleTimeUp.bind(this))
   }
 
   init() {
@@ -101,6 +123,41 @@ export class BevyEventBridge {
     }
   }
 
+  private handleAssetLoadFailed(event: BevyToJsEvent): void {
+    console.error(`🖼️ Asset failed to load: ${event.url} (critter: ${event.critter_id ?? 'n/a'})`, event.error)
+    // Re-broadcast for UI components (broken-sprite indicator, retry button)
+    window.dispatchEvent(new CustomEvent('app4dog-asset-load-failed', {
+      detail: { url: event.url, error: event.error, critterId: event.critter_id ?? null }
+    }))
+  }
+
+  private handleScoreChanged(event: BevyToJsEvent): void {
+    // Re-broadcast so score widgets can animate their counters
+    window.dispatchEvent(new CustomEvent('app4dog-score-changed', {
+      detail: { delta: event.delta, total: event.total, level: event.level }
+    }))
+  }
+
+  private handleLifeLost(event: BevyToJsEvent): void {
+    window.dispatchEvent(new CustomEvent('app4dog-life-lost', {
+      detail: { livesLeft: event.lives_left }
+    }))
+  }
+
+  private handleTimeUp(event: BevyToJsEvent): void {
+    window.dispatchEvent(new CustomEvent('app4dog-time-up', {
+      detail: { finalScore: event.final_score }
+    }))
+  }
+
+  private handleEngineError(event: BevyToJsEvent): void {
+    console.error(`🚫 Engine error [${event.code}]: ${event.message}`)
+    // e.g. code "no_critters" -> UI shows "no content available"; "catalog_timeout" -> offer a retry (reload_catalog())
+    window.dispatchEvent(new CustomEvent('app4dog-engine-error', {
+      detail: { code: event.code, message: event.message }
+    }))
+  }
+
   destroy() {
     if (!this.isInitialized) return
     
@@ -6,12 +6,13 @@ import { ref, onMounted, onUnmounted } from 'vue'
 
 // TypeScript types matching the Rust AudioPlugin events
 export interface AudioRequest {
-  type: 'Play' | 'Stop' | 'SetVolume' | 'Test'
+  type: 'Play' | 'Stop' | 'SetVolume' | 'Test' | 'Pause' | 'Resume'
   request_id: string
   sound_id?: string
   context?: 'Enter' | 'Exit' | 'UI' | 'Critter' | 'Ambient' | 'Test'
   volume?: number
   loop_audio?: boolean
+  source_url?: string
   test_type?: string
 }
 
@@ -45,6 +46,8 @@ export class NativeAudioHandler {
     this.eventHandlers.set('Stop', this.handleStopRequest.bind(this))
     this.eventHandlers.set('SetVolume', this.handleVolumeRequest.bind(this))
     this.eventHandlers.set('Test', this.handleTestRequest.bind(this))
+    this.eventHandlers.set('Pause', this.handlePauseRequest.bind(this))
+    this.eventHandlers.set('Resume', this.handleResumeRequest.bind(this))
   }
 
   private setupContextVolumes() {
@@ -121,7 +124,7 @@ export class NativeAudioHandler {
 
   private coerceLegacyRequest(raw: unknown): AudioRequest | null {
     if (!raw || typeof raw !== 'object') return null
-    const keys = ['Play', 'Stop', 'SetVolume', 'Test'] as const
+    const keys = ['Play', 'Stop', 'SetVolume', 'Test', 'Pause', 'Resume'] as const
     for (const k of keys) {
       const rec = (raw as Record<string, unknown>)[k]
       if (rec && typeof rec === 'object') {
@@ -145,7 +148,8 @@ export class NativeAudioHandler {
         request.sound_id, 
         request.volume || 0.8,
         request.context || 'Test',
-        request.loop_audio || false
+        request.loop_audio || false,
+        request.source_url
       )
       const elapsed = performance.now() - startTime
       
@@ -204,6 +208,23 @@ export class NativeAudioHandler {
     }
   }
 
+  // Mute pauses looping tracks in place so unmute picks them up where they were; Bevy expects no reply
+  private handlePauseRequest(request: AudioRequest): void {
+    const audio = request.sound_id ? this.audioCache.get(request.sound_id) : undefined
+    if (audio) {
+      console.log(`⏸️ Pausing audio: ${request.sound_id}`)
+      audio.pause()
+    }
+  }
+
+  private handleResumeRequest(request: AudioRequest): void {
+    const audio = request.sound_id ? this.audioCache.get(request.sound_id) : undefined
+    if (audio && audio.paused) {
+      console.log(`▶️ Resuming audio: ${request.sound_id}`)
+      audio.play().catch((error) => console.warn('⚠️ Failed to resume audio:', error))
+    }
+  }
+
   private handleVolumeRequest(request: AudioRequest): void {
     const newVolume = request.volume || 1.0
     console.log(`🔊 Setting global volume: ${newVolume}`)
@@ -245,7 +266,8 @@ export class NativeAudioHandler {
     soundId: string, 
     volume: number, 
     context: string,
-    loop: boolean
+    loop: boolean,
+    sourceUrl?: string
   ): Promise<number> {
     // Get context-specific volume adjustment
     const contextVolume = this.contextVolumes.get(context) || 1.0
@@ -254,6 +276,10 @@ export class NativeAudioHandler {
     // Build audio file candidates based on sound ID and context
     const base = import.meta.env.BASE_URL
     const candidates = this.buildAudioCandidates(soundId, base)
+    // Engine-selected source (already checked against canPlayType) goes first
+    if (sourceUrl) {
+      candidates.unshift(/^(https?:)?\//.test(sourceUrl) ? sourceUrl : `${base}${sourceUrl}`)
+    }
 
     let lastError: unknown = null
     
@@ -4,6 +4,10 @@ export interface SharedSettings {
   music_enabled: boolean
   bgm_volume: number // 0..1
   sfx_volume: number // 0..1
+  // Per-context mix (0..1), scaled by sfx_volume; unset contexts keep their current bus gain
+  ui_volume?: number
+  critter_volume?: number
+  ambient_volume?: number
 }
 
 const STORAGE_KEY = 'a4d-settings-v1'
@@ -17,6 +21,9 @@ function loadSettings(): SharedSettings {
         music_enabled: Boolean(parsed.music_enabled ?? true),
         bgm_volume: clamp01(Number(parsed.bgm_volume ?? 0.6)),
         sfx_volume: clamp01(Number(parsed.sfx_volume ?? 0.8)),
+        ui_volume: optionalVolume(parsed.ui_volume),
+        critter_volume: optionalVolume(parsed.critter_volume),
+        ambient_volume: optionalVolume(parsed.ambient_volume),
       }
     }
   } catch (e) {
@@ -27,6 +34,8 @@ function loadSettings(): SharedSettings {
 
 function clamp01(v: number): number { return Math.max(0, Math.min(1, isFinite(v) ? v : 0)) }
 
+function optionalVolume(v: unknown): number | undefined { return v == null ? undefined : clamp01(Number(v)) }
+
 const settings = reactive<SharedSettings>(loadSettings())
 
 function persist() {
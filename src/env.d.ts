@@ -50,7 +50,7 @@ declare global {
     send_event_to_bevy?: (eventJson: string) => void
     send_js_to_bevy_event?: (eventJson: string) => void
     // Camera: submit raw frame bytes (RGB or YUV bytes)
-    submit_camera_frame?: (width: number, height: number, data: Uint8Array, ts: number) => void
+    submit_camera_frame?: (width: number, height: number, data: Uint8Array, ts: number, sourceId?: string, orientation?: number) => void
     
     // Audio response functions  
     send_audio_response?: (responseJson: string) => void
@@ -59,6 +59,7 @@ declare global {
     // Critter management functions (for CritterSelection component)
     critters_ready?: () => boolean
     get_available_critters?: () => unknown[]
+    prefetch_catalog?: () => void
     
     // Other WASM exports - allow any additional functions
     [key: string]: unknown
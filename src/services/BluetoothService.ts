@@ -142,6 +142,11 @@ export interface ZephyrResponse {
     timestamp: number
   }
   FoodLevel?: { current_grams: number, capacity_grams: number }
+  RawData?: {
+    service_uuid: string
+    characteristic_uuid: string
+    data_hex: string
+  }
   Success?: Record<string, never>
   Error?: { code: number, message: string }
 }
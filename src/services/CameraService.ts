@@ -8,6 +8,7 @@ export interface CameraFrame {
   width: number
   height: number
   ts: number
+  orientation?: 0 | 90 | 180 | 270 // clockwise rotation that makes the frame upright
 }
 
 type CameraPreviewPlugin = {
@@ -260,7 +261,7 @@ export class CameraService {
     const submit = wasm?.submit_camera_frame
     if (!submit) return
     try {
-      submit(frame.width, frame.height, frame.data, frame.ts)
+      submit(frame.width, frame.height, frame.data, frame.ts, undefined, frame.orientation)
     } catch (e) {
       console.error('Failed to submit camera frame to WASM:', e)
     }